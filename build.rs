@@ -0,0 +1,17 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::env;
+
+/// Leaves the solution modules listed in `AOC_SKIP` out of the build, e.g. `AOC_SKIP=day07,y2021::day03`.
+/// The `aoc` runner sets it when it builds its workers without the days that do not compile.
+fn main() {
+    println!("cargo::rerun-if-env-changed=AOC_SKIP");
+    println!("cargo::rustc-check-cfg=cfg(aoc_skip, values(any()))");
+    if let Ok(skip) = env::var("AOC_SKIP") {
+        for module in skip.split(',').filter(|module| !module.is_empty()) {
+            println!("cargo::rustc-cfg=aoc_skip=\"{}\"", module);
+        }
+    }
+}
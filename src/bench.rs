@@ -0,0 +1,160 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::memory::{self, MemoryStats};
+use serde::{Deserialize, Serialize};
+use std::{
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+pub const DEFAULT_WARMUP: u32 = 3;
+pub const DEFAULT_ITERATIONS: u32 = 100;
+pub const DEFAULT_BUDGET: Duration = Duration::from_secs(5);
+
+/// How often and how long solutions are run in bench mode.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BenchOptions {
+    /// untimed runs before measuring.
+    pub warmup: u32,
+    /// maximum number of timed runs.
+    pub iterations: u32,
+    /// time available for all parts of one day, including warmup.
+    pub budget: Duration,
+}
+
+impl Default for BenchOptions {
+    fn default() -> Self {
+        BenchOptions {
+            warmup: DEFAULT_WARMUP,
+            iterations: DEFAULT_ITERATIONS,
+            budget: DEFAULT_BUDGET,
+        }
+    }
+}
+
+/// Timing statistics of repeated runs of one part.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Stats {
+    pub samples: u32,
+    pub min_ns: u64,
+    pub median_ns: u64,
+    pub mean_ns: u64,
+    pub stddev_ns: u64,
+}
+
+impl Stats {
+    /// Computes statistics from a non-empty list of samples.
+    pub fn from_samples(samples: &[Duration]) -> Stats {
+        let mut nanos: Vec<f64> = samples.iter().map(|s| s.as_nanos() as f64).collect();
+        nanos.sort_by(|a, b| a.total_cmp(b));
+
+        let count = nanos.len();
+        let median = if count.is_multiple_of(2) {
+            (nanos[count / 2 - 1] + nanos[count / 2]) / 2.0
+        } else {
+            nanos[count / 2]
+        };
+        let mean = nanos.iter().sum::<f64>() / count as f64;
+        let variance = if count > 1 {
+            nanos.iter().map(|n| (n - mean).powi(2)).sum::<f64>() / (count - 1) as f64
+        } else {
+            0.0
+        };
+
+        Stats {
+            samples: count as u32,
+            min_ns: nanos[0] as u64,
+            median_ns: median.round() as u64,
+            mean_ns: mean.round() as u64,
+            stddev_ns: variance.sqrt().round() as u64,
+        }
+    }
+
+    pub fn min(&self) -> Duration {
+        Duration::from_nanos(self.min_ns)
+    }
+
+    pub fn median(&self) -> Duration {
+        Duration::from_nanos(self.median_ns)
+    }
+
+    pub fn mean(&self) -> Duration {
+        Duration::from_nanos(self.mean_ns)
+    }
+
+    pub fn stddev(&self) -> Duration {
+        Duration::from_nanos(self.stddev_ns)
+    }
+}
+
+/// Runs `func` `options.warmup` times, then measures up to `options.iterations` runs.
+/// Stops early once `budget` is used up, but always measures at least one run.
+/// Returns the result of the last run together with the statistics and the heap usage of the first measured run.
+pub fn measure<T>(
+    func: impl Fn() -> T,
+    options: &BenchOptions,
+    budget: Duration,
+) -> (T, Stats, Option<MemoryStats>) {
+    let start = Instant::now();
+    let in_budget = || start.elapsed() < budget;
+
+    for _ in 0..options.warmup {
+        if !in_budget() {
+            break;
+        }
+        black_box(func());
+    }
+
+    let ((mut result, first), memory) = memory::tracked(|| {
+        let timer = Instant::now();
+        (black_box(func()), timer.elapsed())
+    });
+    let mut samples = vec![first];
+
+    while (samples.len() as u32) < options.iterations && in_budget() {
+        let timer = Instant::now();
+        result = black_box(func());
+        samples.push(timer.elapsed());
+    }
+
+    (result, Stats::from_samples(&samples), memory)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stats_from_samples() {
+        let samples: Vec<Duration> = [40, 10, 30, 20]
+            .into_iter()
+            .map(Duration::from_nanos)
+            .collect();
+        let stats = Stats::from_samples(&samples);
+        assert_eq!(stats.samples, 4);
+        assert_eq!(stats.min_ns, 10);
+        assert_eq!(stats.median_ns, 25);
+        assert_eq!(stats.mean_ns, 25);
+        assert_eq!(stats.stddev_ns, 13);
+
+        let stats = Stats::from_samples(&[Duration::from_nanos(7)]);
+        assert_eq!((stats.median_ns, stats.mean_ns, stats.stddev_ns), (7, 7, 0));
+    }
+
+    #[test]
+    fn test_measure_respects_limits() {
+        let options = BenchOptions {
+            warmup: 2,
+            iterations: 5,
+            budget: Duration::from_secs(60),
+        };
+        let (result, stats, _) = measure(|| 42, &options, options.budget);
+        assert_eq!(result, 42);
+        assert_eq!(stats.samples, 5);
+
+        let (_, stats, _) = measure(|| 42, &options, Duration::ZERO);
+        assert_eq!(stats.samples, 1);
+    }
+}
@@ -0,0 +1,112 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use aoc::{history, memory::format_bytes, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+use std::{process, time::Duration};
+
+const DEFAULT_THRESHOLD: f64 = 20.0;
+
+struct Args {
+    baseline: Option<String>,
+    threshold: f64,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    let parsed = Args {
+        baseline: args.opt_value_from_str(["-b", "--baseline"])?,
+        threshold: args
+            .opt_value_from_str(["-t", "--threshold"])?
+            .unwrap_or(DEFAULT_THRESHOLD),
+    };
+    if let Some(unknown) = args.finish().first() {
+        return Err(pico_args::Error::ArgumentParsingFailed {
+            cause: format!("unexpected argument {:?}", unknown),
+        });
+    }
+    Ok(parsed)
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {}", e);
+            process::exit(1);
+        }
+    };
+
+    let entries = match history::load() {
+        Ok(entries) => entries,
+        Err(e) => {
+            eprintln!("{}", e);
+            process::exit(1);
+        }
+    };
+
+    let Some(latest) = entries.last() else {
+        eprintln!("No benchmark history found. Run \"cargo bench-day\" first.");
+        process::exit(1);
+    };
+
+    println!(
+        "Comparing run of {} ({}) against {}, threshold {}%:",
+        latest.short_commit(),
+        latest.date,
+        args.baseline.as_deref().unwrap_or("previous runs"),
+        args.threshold
+    );
+
+    let comparisons = history::compare(&entries, args.baseline.as_deref());
+    if comparisons.is_empty() {
+        println!("No earlier timings to compare against.");
+        return;
+    }
+
+    let mut regressions = vec![];
+    for comparison in &comparisons {
+        let regressed = comparison.is_regression(args.threshold);
+        println!(
+            "{} Day {:02}, {}: {:.2?} → {:.2?} {}({:.2}x, baseline {}){}",
+            if regressed { "✗" } else { "✓" },
+            comparison.day,
+            match comparison.part {
+                0 => "parse".to_string(),
+                part => format!("part {}", part),
+            },
+            Duration::from_nanos(comparison.baseline_ns),
+            Duration::from_nanos(comparison.latest_ns),
+            ANSI_ITALIC,
+            comparison.ratio(),
+            comparison.baseline_commit,
+            ANSI_RESET
+        );
+        if let (Some((baseline, latest)), Some(ratio)) =
+            (comparison.peak_bytes, comparison.memory_ratio())
+        {
+            println!(
+                "  peak heap: {} → {} {}({:.2}x){}",
+                format_bytes(baseline),
+                format_bytes(latest),
+                ANSI_ITALIC,
+                ratio,
+                ANSI_RESET
+            );
+        }
+        if regressed {
+            regressions.push(format!("{:02}", comparison.day));
+        }
+    }
+
+    regressions.dedup();
+    if regressions.is_empty() {
+        println!("{}No regressions.{}", ANSI_BOLD, ANSI_RESET);
+    } else {
+        println!(
+            "Slower or larger than threshold: day(s) {}",
+            regressions.join(", ")
+        );
+        process::exit(1);
+    }
+}
@@ -0,0 +1,136 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use aoc::{config, crypt};
+use std::{
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    path::{Path, PathBuf},
+    process,
+};
+
+enum Command {
+    /// create a new key file.
+    Keygen,
+    /// encrypt the inputs of all days or of one day in place, including team inputs.
+    Encrypt(Option<u8>),
+    /// print the decrypted input of a day.
+    Decrypt(u8),
+}
+
+fn parse_args() -> Result<Command, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    let command: String = args.free_from_str()?;
+    let parsed = match command.as_str() {
+        "keygen" => Command::Keygen,
+        "encrypt" => Command::Encrypt(args.opt_free_from_str()?),
+        "decrypt" => Command::Decrypt(args.free_from_str()?),
+        _ => {
+            return Err(pico_args::Error::ArgumentParsingFailed {
+                cause: format!(
+                    "unknown command {:?}, use keygen, encrypt or decrypt",
+                    command
+                ),
+            })
+        }
+    };
+    if let Some(unknown) = args.finish().first() {
+        return Err(pico_args::Error::ArgumentParsingFailed {
+            cause: format!("unexpected argument {:?}", unknown),
+        });
+    }
+    Ok(parsed)
+}
+
+fn exit_with(message: &str) -> ! {
+    eprintln!("{}", message);
+    process::exit(1);
+}
+
+fn keygen(path: &Path) {
+    let file = OpenOptions::new().write(true).create_new(true).open(path);
+    match file.and_then(|mut file| writeln!(file, "{}", crypt::generate_key())) {
+        Ok(_) => {
+            println!("Created key file \"{}\".", path.display());
+            println!("Share it with your team outside of git, e.g. via a password manager.");
+        }
+        Err(e) if e.kind() == io::ErrorKind::AlreadyExists => exit_with(&format!(
+            "Key file \"{}\" already exists, not overwriting it.",
+            path.display()
+        )),
+        Err(e) => exit_with(&format!("Failed to create key file: {}", e)),
+    }
+}
+
+/// Returns the input files of `day`, or of all days: `NN.txt` and team inputs in `NN/`.
+fn input_files(dir: &Path, day: Option<u8>) -> io::Result<Vec<PathBuf>> {
+    let mut files = vec![];
+    let is_txt = |path: &Path| path.extension().is_some_and(|ext| ext == "txt");
+    let matches_day = |path: &Path| {
+        let stem = path.file_stem().and_then(|stem| stem.to_str());
+        day.is_none_or(|day| stem.and_then(|stem| stem.parse().ok()) == Some(day))
+    };
+
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_dir() && matches_day(&path) {
+            for entry in fs::read_dir(&path)? {
+                let path = entry?.path();
+                if is_txt(&path) {
+                    files.push(path);
+                }
+            }
+        } else if is_txt(&path) && matches_day(&path) {
+            files.push(path);
+        }
+    }
+    files.sort();
+    Ok(files)
+}
+
+fn encrypt(dir: &Path, day: Option<u8>) {
+    let key = crypt::load_key().unwrap_or_else(|e| exit_with(&e.to_string()));
+    let files = input_files(dir, day)
+        .unwrap_or_else(|e| exit_with(&format!("Failed to list inputs: {}", e)));
+
+    let mut encrypted = 0;
+    for path in files {
+        let content = fs::read_to_string(&path)
+            .unwrap_or_else(|e| exit_with(&format!("Failed to read {}: {}", path.display(), e)));
+        if crypt::is_encrypted(&content) || content.trim().is_empty() {
+            continue;
+        }
+        if let Err(e) = fs::write(&path, crypt::encrypt(&content, &key)) {
+            exit_with(&format!("Failed to write {}: {}", path.display(), e));
+        }
+        println!("Encrypted \"{}\"", path.display());
+        encrypted += 1;
+    }
+    println!("---");
+    println!("🎄 Encrypted {} input(s).", encrypted);
+}
+
+fn decrypt(day: u8) {
+    match aoc::read_file("inputs", day) {
+        Ok(input) => println!("{}", input),
+        Err(e) => exit_with(&e.to_string()),
+    }
+}
+
+fn main() {
+    let command = match parse_args() {
+        Ok(command) => command,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {}", e);
+            process::exit(1);
+        }
+    };
+
+    let config = config::get();
+    match command {
+        Command::Keygen => keygen(&config.key_file),
+        Command::Encrypt(day) => encrypt(&config.dir("inputs", config.year), day),
+        Command::Decrypt(day) => decrypt(day),
+    }
+}
@@ -2,20 +2,31 @@
  * This file contains template code.
  * There is no need to edit this file unless you want to change template functionality.
  */
-use aoc::aoc_cli;
+use aoc::{client, schedule};
 use std::process;
 
 struct Args {
-    day: u8,
+    day: Option<u8>,
     year: Option<u16>,
+    wait: bool,
+    /// overwrite existing files that differ from the download.
+    force: bool,
 }
 
 fn parse_args() -> Result<Args, pico_args::Error> {
     let mut args = pico_args::Arguments::from_env();
-    Ok(Args {
-        day: args.free_from_str()?,
+    let parsed = Args {
+        wait: args.contains("--wait"),
+        force: args.contains("--force"),
         year: args.opt_value_from_str(["-y", "--year"])?,
-    })
+        day: args.opt_free_from_str()?,
+    };
+    if let Some(unknown) = args.finish().first() {
+        return Err(pico_args::Error::ArgumentParsingFailed {
+            cause: format!("unexpected argument {:?}", unknown),
+        });
+    }
+    Ok(parsed)
 }
 
 fn main() {
@@ -27,20 +38,29 @@ fn main() {
         }
     };
 
-    if aoc_cli::check().is_err() {
-        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
-        process::exit(1);
-    }
-
-    match aoc_cli::download(args.day, args.year) {
-        Ok(cmd_output) => {
-            if !cmd_output.status.success() {
-                process::exit(1);
-            }
-        }
+    let year = args.year.unwrap_or_else(client::default_year);
+    let day = match schedule::resolve_day(args.day, year, chrono::Utc::now()) {
+        Ok(day) => day,
         Err(e) => {
-            eprintln!("failed to spawn aoc-cli: {}", e);
+            eprintln!("{} example: `cargo download 7`", e);
             process::exit(1);
         }
+    };
+    if let Some(wait) = schedule::time_until_unlock(year, day, chrono::Utc::now()) {
+        if !args.wait {
+            eprintln!(
+                "Day {} of {} unlocks in {}. Use `--wait` to download it once it unlocks.",
+                day,
+                year,
+                schedule::format_countdown(wait)
+            );
+            process::exit(1);
+        }
+        schedule::wait_for_unlock(year, day);
+    }
+
+    if let Err(e) = client::download(day, args.year, args.force) {
+        eprintln!("failed to download day {}: {}", day, e);
+        process::exit(1);
     }
-}
\ No newline at end of file
+}
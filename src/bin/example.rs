@@ -0,0 +1,124 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use aoc::{client, config, example, schedule};
+use std::{fs, io, path::Path, process};
+
+struct Args {
+    day: Option<u8>,
+    /// overwrite an example file that is not empty.
+    force: bool,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    let parsed = Args {
+        force: args.contains("--force"),
+        day: args.opt_free_from_str()?,
+    };
+    if let Some(unknown) = args.finish().first() {
+        return Err(pico_args::Error::ArgumentParsingFailed {
+            cause: format!("unexpected argument {:?}", unknown),
+        });
+    }
+    Ok(parsed)
+}
+
+fn exit_with(message: &str) -> ! {
+    eprintln!("{}", message);
+    process::exit(1);
+}
+
+fn read_optional(path: &Path) -> String {
+    match fs::read_to_string(path) {
+        Ok(content) => content,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => String::new(),
+        Err(e) => exit_with(&format!("Failed to read \"{}\": {}", path.display(), e)),
+    }
+}
+
+fn write_example(path: &Path, example: &str, force: bool) {
+    let existing = read_optional(path);
+    if existing.trim_end() == example {
+        println!("Example file \"{}\" is up to date", path.display());
+        return;
+    }
+    if !existing.trim().is_empty() && !force {
+        println!(
+            "Kept existing example file \"{}\", use `--force` to overwrite it",
+            path.display()
+        );
+        return;
+    }
+    if let Some(dir) = path.parent() {
+        if let Err(e) = fs::create_dir_all(dir) {
+            exit_with(&format!("Failed to create data directory: {}", e));
+        }
+    }
+    if let Err(e) = fs::write(path, example) {
+        exit_with(&format!("Failed to write example file: {}", e));
+    }
+    println!("Wrote example to \"{}\"", path.display());
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {}", e);
+            process::exit(1);
+        }
+    };
+
+    let year = client::default_year();
+    let day = match schedule::resolve_day(args.day, year, chrono::Utc::now()) {
+        Ok(day) => day,
+        Err(e) => exit_with(&format!("{} example: `cargo example 7`", e)),
+    };
+
+    let config = config::get();
+    let puzzle_path = config.path("puzzles", day, "md");
+    let puzzle = match fs::read_to_string(&puzzle_path) {
+        Ok(puzzle) => puzzle,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => exit_with(&format!(
+            "No puzzle found at \"{}\", run `cargo download {}` first.",
+            puzzle_path.display(),
+            day
+        )),
+        Err(e) => exit_with(&format!("Failed to read puzzle: {}", e)),
+    };
+    let extract = example::extract(&puzzle);
+
+    let example_path = config.path("examples", day, "txt");
+    match &extract.example {
+        Some(code) => write_example(&example_path, code, args.force),
+        None => println!("No example found in the puzzle"),
+    }
+
+    // like the registry, fall back to the days scaffolded without a year.
+    let module_path = Some(config.module_path(day, config.year))
+        .filter(|path| path.exists())
+        .unwrap_or_else(|| config.module_path(day, None));
+    let module = read_optional(&module_path);
+    let mut filled = module.clone();
+    // the scaffolded tests are ignored until there is an example to run them on.
+    if !read_optional(&example_path).trim().is_empty() {
+        filled = example::enable_tests(&filled);
+    }
+    for (part, answer) in [(1, &extract.part_one), (2, &extract.part_two)] {
+        match answer {
+            Some(answer) => {
+                println!("Expected answer of part {}: {}", part, answer);
+                filled = example::fill_test(&filled, part, answer);
+            }
+            None => println!("No answer found for part {}", part),
+        }
+    }
+    if filled != module {
+        if let Err(e) = fs::write(&module_path, filled) {
+            exit_with(&format!("Failed to write module file: {}", e));
+        }
+        println!("Updated the tests of \"{}\"", module_path.display());
+    }
+}
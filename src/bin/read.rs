@@ -0,0 +1,49 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use aoc::{client, schedule};
+use std::process;
+
+struct Args {
+    day: Option<u8>,
+    year: Option<u16>,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    let parsed = Args {
+        year: args.opt_value_from_str(["-y", "--year"])?,
+        day: args.opt_free_from_str()?,
+    };
+    if let Some(unknown) = args.finish().first() {
+        return Err(pico_args::Error::ArgumentParsingFailed {
+            cause: format!("unexpected argument {:?}", unknown),
+        });
+    }
+    Ok(parsed)
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {}", e);
+            process::exit(1);
+        }
+    };
+
+    let year = args.year.unwrap_or_else(client::default_year);
+    let day = match schedule::resolve_day(args.day, year, chrono::Utc::now()) {
+        Ok(day) => day,
+        Err(e) => {
+            eprintln!("{} example: `cargo read 7`", e);
+            process::exit(1);
+        }
+    };
+
+    if let Err(e) = client::read(day, args.year) {
+        eprintln!("failed to read day {}: {}", day, e);
+        process::exit(1);
+    }
+}
@@ -0,0 +1,114 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use aoc::{
+    history,
+    ledger::Ledger,
+    readme::{self, STARS_MARKER, TIMINGS_MARKER},
+};
+use chrono::Datelike;
+use std::{fs, process};
+
+const README_FILE: &str = "README.md";
+
+struct Args {
+    year: Option<u16>,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    let parsed = Args {
+        year: args.opt_value_from_str(["-y", "--year"])?,
+    };
+    if let Some(unknown) = args.finish().first() {
+        return Err(pico_args::Error::ArgumentParsingFailed {
+            cause: format!("unexpected argument {:?}", unknown),
+        });
+    }
+    Ok(parsed)
+}
+
+/// Reads the year from the `## YYYY Results` heading of the current table.
+fn current_year(readme: &str) -> Option<u16> {
+    readme.lines().find_map(|line| {
+        line.strip_prefix("## ")?
+            .strip_suffix(" Results")?
+            .parse()
+            .ok()
+    })
+}
+
+fn exit_with(message: &str) -> ! {
+    eprintln!("{}", message);
+    process::exit(1);
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {}", e);
+            process::exit(1);
+        }
+    };
+
+    let config = aoc::config::get();
+    let readme_path = config.root.join(README_FILE);
+    let content = fs::read_to_string(&readme_path)
+        .unwrap_or_else(|e| exit_with(&format!("could not read {}: {}", readme_path.display(), e)));
+
+    let year = args
+        .year
+        .or_else(|| current_year(&content))
+        .or(config.year)
+        .unwrap_or_else(|| chrono::Local::now().year() as u16);
+
+    let ledgers: Vec<(u8, Ledger)> = (1..=25)
+        .map(|day| match Ledger::load(day, config.year) {
+            Ok(ledger) => (day, ledger),
+            Err(e) => exit_with(&e.to_string()),
+        })
+        .collect();
+
+    let entries = history::load().unwrap_or_else(|e| exit_with(&e.to_string()));
+    let timings = readme::latest_timings(&entries);
+
+    let existing = readme::existing_stars(&content, year);
+    let stars = readme::stars_table(year, &ledgers, &existing);
+    let Some(mut content) = readme::replace_section(&content, STARS_MARKER, &stars) else {
+        exit_with(&format!(
+            "{} does not contain two \"{}\" markers.",
+            readme_path.display(),
+            STARS_MARKER
+        ));
+    };
+
+    if timings.is_empty() {
+        println!(
+            "No benchmark history found, skipping the timing table. Run \"cargo bench-day\" first."
+        );
+    } else {
+        if !content.contains(TIMINGS_MARKER) {
+            content = readme::insert_section(&content, STARS_MARKER, TIMINGS_MARKER).unwrap();
+        }
+        content = readme::replace_section(
+            &content,
+            TIMINGS_MARKER,
+            &readme::timings_table(&timings, config.year),
+        )
+        .unwrap_or_else(|| {
+            exit_with(&format!(
+                "{} does not contain two \"{}\" markers.",
+                readme_path.display(),
+                TIMINGS_MARKER
+            ))
+        });
+    }
+
+    if let Err(e) = fs::write(&readme_path, content) {
+        exit_with(&format!("could not write {}: {}", readme_path.display(), e));
+    }
+
+    println!("Updated {} with {} results.", readme_path.display(), year);
+}
@@ -2,110 +2,402 @@
  * This file contains template code.
  * There is no need to edit this file unless you want to change template functionality.
  */
+use aoc::{client, config, schedule};
 use std::{
-    fs::{File, OpenOptions},
-    io::Write,
+    fs::{self, File, OpenOptions},
+    io::{self, Write},
+    path::Path,
     process,
 };
 
-const MODULE_TEMPLATE: &str = r###"pub fn part_one(input: &str) -> Option<u32> {
-    None
+const TEMPLATES_DIR: &str = "templates";
+const DEFAULT_TEMPLATE: &str = "default";
+const DEFAULT_ANSWER_TYPE: &str = "u32";
+
+const YEAR_REGISTRY: &str = "/*
+ * Solutions of YEAR, registered here so that the `aoc` runner can call every day in-process.
+ * `cargo scaffold --year YEAR` adds new days to this file.
+ */
+use crate::solution::Day;
+
+/// Returns all registered solutions of YEAR.
+pub fn registry() -> Vec<Day> {
+    vec![
+    ]
 }
+";
 
-pub fn part_two(input: &str) -> Option<u32> {
-    None
+struct Args {
+    day: Option<u8>,
+    /// put the solution into the folder of this year, defaults to the configured year.
+    year: Option<u16>,
+    /// wait until the puzzle unlocks, then scaffold and download it.
+    wait: bool,
+    /// download the input and puzzle after scaffolding.
+    download: bool,
+    /// name of the folder in `templates/` to scaffold from.
+    template: String,
+    /// type of the answers of both parts.
+    answer_type: String,
 }
 
-fn main() {
-    let input = &aoc::read_file("inputs", DAY);
-    aoc::solve!(DAY, 1, part_one, input);
-    aoc::solve!(DAY, 2, part_two, input);
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    let parsed = Args {
+        year: args.opt_value_from_str(["-y", "--year"])?,
+        wait: args.contains("--wait"),
+        download: args.contains("--download"),
+        template: args
+            .opt_value_from_str("--template")?
+            .unwrap_or_else(|| DEFAULT_TEMPLATE.to_string()),
+        answer_type: args
+            .opt_value_from_str("--answer-type")?
+            .unwrap_or_else(|| DEFAULT_ANSWER_TYPE.to_string()),
+        day: args.opt_free_from_str()?,
+    };
+    if let Some(unknown) = args.finish().first() {
+        return Err(pico_args::Error::ArgumentParsingFailed {
+            cause: format!("unexpected argument {:?}", unknown),
+        });
+    }
+    Ok(parsed)
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+fn safe_create_file(path: &Path) -> Result<File, std::io::Error> {
+    OpenOptions::new().write(true).create_new(true).open(path)
+}
+
+fn create_file(path: &Path) -> Result<File, std::io::Error> {
+    OpenOptions::new()
+        .write(true)
+        .create(true)
+        .truncate(true)
+        .open(path)
+}
 
-    #[test]
-    fn test_part_one() {
-        let input = aoc::read_file("examples", DAY);
-        assert_eq!(part_one(&input), None);
+/// Reads `templates/<name>/<file>`. Templates without a `bin.rs` use the one of the default template.
+fn load_template(name: &str, file: &str) -> Result<String, String> {
+    let path = config::get().root.join(TEMPLATES_DIR).join(name).join(file);
+    match fs::read_to_string(&path) {
+        Ok(template) => Ok(template),
+        Err(e)
+            if e.kind() == io::ErrorKind::NotFound
+                && file == "bin.rs"
+                && name != DEFAULT_TEMPLATE =>
+        {
+            load_template(DEFAULT_TEMPLATE, file)
+        }
+        Err(e) => Err(format!(
+            "Failed to read template \"{}\": {}",
+            path.display(),
+            e
+        )),
     }
+}
+
+/// Fills the placeholders of a template. `data_year` is the year of the data folder, `year` the one of the event.
+fn fill_template(
+    template: &str,
+    module: &str,
+    data_year: Option<u16>,
+    day: u8,
+    year: u16,
+    answer_type: &str,
+) -> String {
+    template
+        .replace("MODULE", module)
+        .replace("DATA_YEAR", &format!("{:?}", data_year))
+        .replace("DAY_PADDED", &format!("{:02}", day))
+        .replace("DAY", &day.to_string())
+        .replace("YEAR", &year.to_string())
+        .replace("ANSWER_TYPE", answer_type)
+}
 
-    #[test]
-    fn test_part_two() {
-        let input = aoc::read_file("examples", DAY);
-        assert_eq!(part_two(&input), None);
+/// Returns where a module declaration is added to a registry: after the last one, or after the imports.
+fn module_position(registry: &str) -> usize {
+    match registry.rfind("pub mod ") {
+        Some(pos) => pos + registry[pos..].find('\n').unwrap() + 1,
+        None => {
+            let imports = registry.rfind("\nuse ").expect("registry has no imports");
+            imports
+                + registry[imports..]
+                    .find("\n\n")
+                    .expect("registry has no imports")
+                + 2
+        }
     }
 }
-"###;
 
-fn parse_args() -> Result<u8, pico_args::Error> {
-    let mut args = pico_args::Arguments::from_env();
-    args.free_from_str()
+/// Adds the solution module of the given day to the registry, e.g. `src/solutions/mod.rs`.
+/// `name` is the module below `solutions`, e.g. `y2021::day07`. The runner leaves it out if it does not compile.
+fn register_module(registry: &str, day_padded: &str, name: &str) -> String {
+    if registry.contains(&format!("pub mod day{};\n", day_padded)) {
+        return registry.to_string();
+    }
+    let skip = format!("#[cfg(not(aoc_skip = \"{}\"))]", name);
+    let module = format!("{}\npub mod day{};\n", skip, day_padded);
+    let entry = format!(
+        "        {}\n        Day::of::<day{1}::Day{1}>(),\n",
+        skip, day_padded
+    );
+
+    let mut registry = registry.to_string();
+
+    let entry_pos = registry
+        .rfind("    ]\n}")
+        .expect("registry has no solution list");
+    registry.insert_str(entry_pos, &entry);
+
+    let first = !registry.contains("pub mod ");
+    let pos = module_position(&registry);
+    registry.insert_str(pos, &module);
+    if first {
+        registry.insert(pos + module.len(), '\n');
+    }
+
+    registry
 }
 
-fn safe_create_file(path: &str) -> Result<File, std::io::Error> {
-    OpenOptions::new().write(true).create_new(true).open(path)
+/// Adds the registry of a year to the list of years in `src/solutions/mod.rs`.
+fn register_year(registry: &str, year: u16) -> String {
+    let module = format!("pub mod y{};\n", year);
+    if registry.contains(&module) {
+        return registry.to_string();
+    }
+
+    let mut registry = registry.to_string();
+
+    let years = registry
+        .find("const YEARS")
+        .expect("registry has no list of years");
+    let end = years
+        + registry[years..]
+            .find("];")
+            .expect("registry has no list of years");
+    let entry = format!("    ({0}, y{0}::registry),\n", year);
+    if registry[..end].ends_with('[') {
+        registry.insert_str(end, &format!("\n{}", entry));
+    } else {
+        registry.insert_str(end, &entry);
+    }
+
+    let first = !registry.contains("pub mod ");
+    let pos = module_position(&registry);
+    registry.insert_str(pos, &module);
+    if first {
+        registry.insert(pos + module.len(), '\n');
+    }
+
+    registry
 }
 
-fn create_file(path: &str) -> Result<File, std::io::Error> {
-    OpenOptions::new().write(true).create(true).open(path)
+/// Creates the registry of a year and adds it to `src/solutions/mod.rs`, unless it exists.
+fn create_year_registry(year: u16) {
+    let config = config::get();
+    let path = config.registry_path(Some(year));
+    if path.exists() {
+        return;
+    }
+
+    let main_path = config.registry_path(None);
+    let result = path
+        .parent()
+        .map_or(Ok(()), fs::create_dir_all)
+        .and_then(|_| fs::write(&path, YEAR_REGISTRY.replace("YEAR", &year.to_string())))
+        .and_then(|_| fs::read_to_string(&main_path))
+        .and_then(|registry| fs::write(&main_path, register_year(&registry, year)));
+    match result {
+        Ok(_) => {
+            println!("Created registry \"{}\"", path.display());
+        }
+        Err(e) => {
+            eprintln!("Failed to create registry of {}: {}", year, e);
+            process::exit(1);
+        }
+    }
+}
+
+fn write_bin_and_register(
+    bin_path: &Path,
+    bin: &str,
+    registry_path: &Path,
+    day_padded: &str,
+    name: &str,
+) {
+    match create_file(bin_path).and_then(|mut file| file.write_all(bin.as_bytes())) {
+        Ok(_) => {
+            println!("Created binary file \"{}\"", bin_path.display());
+        }
+        Err(e) => {
+            eprintln!("Failed to write binary contents: {}", e);
+            process::exit(1);
+        }
+    }
+
+    match fs::read_to_string(registry_path)
+        .and_then(|registry| fs::write(registry_path, register_module(&registry, day_padded, name)))
+    {
+        Ok(_) => {
+            println!("Registered module in \"{}\"", registry_path.display());
+        }
+        Err(e) => {
+            eprintln!("Failed to register module: {}", e);
+            process::exit(1);
+        }
+    }
 }
 
 fn main() {
-    let day = match parse_args() {
-        Ok(day) => day,
-        Err(_) => {
-            eprintln!("Need to specify a day (as integer). example: `cargo scaffold 7`");
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {}", e);
             process::exit(1);
         }
     };
 
-    let day_padded = format!("{:02}", day);
+    let config = config::get();
+    // solutions of a year live in their own folder, see `config::solutions_dir`.
+    let solutions_year = args.year.or(config.year);
+    let year = solutions_year.unwrap_or_else(client::default_year);
 
-    let input_path = format!("src/inputs/{}.txt", day_padded);
-    let example_path = format!("src/examples/{}.txt", day_padded);
-    let module_path = format!("src/bin/{}.rs", day_padded);
-
-    let mut file = match safe_create_file(&module_path) {
-        Ok(file) => file,
+    let day = match schedule::resolve_day(args.day, year, chrono::Utc::now()) {
+        Ok(day) => day,
         Err(e) => {
-            eprintln!("Failed to create module file: {}", e.kind());
-            process::exit(0);
+            eprintln!("{} example: `cargo scaffold 7`", e);
+            process::exit(1);
         }
     };
 
-    match file.write_all(MODULE_TEMPLATE.replace("DAY", &day.to_string()).as_bytes()) {
-        Ok(_) => {
-            println!("Created module file \"{}\"", &module_path);
+    let (module_template, bin_template) = match (
+        load_template(&args.template, "module.rs"),
+        load_template(&args.template, "bin.rs"),
+    ) {
+        (Ok(module), Ok(bin)) => (module, bin),
+        (Err(e), _) | (_, Err(e)) => {
+            eprintln!("{}", e);
+            process::exit(1);
         }
-        Err(e) => {
-            eprintln!("Failed to write module contents: {}", e);
+    };
+    if args.wait {
+        schedule::wait_for_unlock(year, day);
+    } else if args.download {
+        // refuse before writing anything, a locked puzzle cannot be downloaded.
+        if let Some(wait) = schedule::time_until_unlock(year, day, chrono::Utc::now()) {
+            eprintln!(
+                "Day {} of {} unlocks in {}. Use `--wait` to scaffold and download it once it unlocks.",
+                day,
+                year,
+                schedule::format_countdown(wait)
+            );
             process::exit(1);
         }
     }
 
-    match create_file(&input_path) {
-        Ok(_) => {
-            println!("Created empty input file \"{}\"", &input_path);
+    let day_padded = format!("{:02}", day);
+
+    let file_name = format!("{}.txt", day_padded);
+    let input_path = config.dir("inputs", solutions_year).join(&file_name);
+    let example_path = config.dir("examples", solutions_year).join(&file_name);
+    let module_path = config.module_path(day, solutions_year);
+    let bin_path = config.bin_path(day, solutions_year);
+    let module = match solutions_year {
+        Some(year) => format!("solutions::y{}::day{}", year, day_padded),
+        None => format!("solutions::day{}", day_padded),
+    };
+
+    if let Some(year) = solutions_year {
+        create_year_registry(year);
+    }
+
+    let download = args.download || args.wait;
+
+    let scaffolded = match safe_create_file(&module_path) {
+        Ok(mut file) => {
+            match file.write_all(
+                fill_template(
+                    &module_template,
+                    &module,
+                    solutions_year,
+                    day,
+                    year,
+                    &args.answer_type,
+                )
+                .as_bytes(),
+            ) {
+                Ok(_) => {
+                    println!("Created module file \"{}\"", module_path.display());
+                }
+                Err(e) => {
+                    eprintln!("Failed to write module contents: {}", e);
+                    process::exit(1);
+                }
+            }
+            true
+        }
+        Err(e) if e.kind() == io::ErrorKind::AlreadyExists && download => {
+            println!(
+                "Module file \"{}\" already exists, only downloading",
+                module_path.display()
+            );
+            false
         }
         Err(e) => {
-            eprintln!("Failed to create input file: {}", e);
+            eprintln!("Failed to create module file: {}", e.kind());
             process::exit(1);
         }
+    };
+
+    if scaffolded {
+        write_bin_and_register(
+            &bin_path,
+            &fill_template(
+                &bin_template,
+                &module,
+                solutions_year,
+                day,
+                year,
+                &args.answer_type,
+            ),
+            &config.registry_path(solutions_year),
+            &day_padded,
+            module.trim_start_matches("solutions::"),
+        );
     }
 
-    match create_file(&example_path) {
-        Ok(_) => {
-            println!("Created empty example file \"{}\"", &example_path);
+    for path in [&input_path, &example_path] {
+        if let Err(e) = path.parent().map_or(Ok(()), fs::create_dir_all) {
+            eprintln!("Failed to create data directory: {}", e);
+            process::exit(1);
         }
-        Err(e) => {
-            eprintln!("Failed to create example file: {}", e);
+    }
+
+    for (kind, path) in [("input", &input_path), ("example", &example_path)] {
+        match safe_create_file(path) {
+            Ok(_) => {
+                println!("Created empty {} file \"{}\"", kind, path.display());
+            }
+            Err(e) if e.kind() == io::ErrorKind::AlreadyExists => {
+                println!("Kept existing {} file \"{}\"", kind, path.display());
+            }
+            Err(e) => {
+                eprintln!("Failed to create {} file: {}", kind, e);
+                process::exit(1);
+            }
+        }
+    }
+
+    if download {
+        println!("---");
+        if let Err(e) = client::download(day, solutions_year, false) {
+            eprintln!("failed to download day {}: {}", day, e);
             process::exit(1);
         }
     }
 
     println!("---");
-    println!("🎄 Type `cargo day {}` to run your solution.", &day_padded);
+    println!(
+        "🎄 Type `cargo solve {}` to run your solution.",
+        config::bin_name(day, solutions_year)
+    );
 }
@@ -0,0 +1,509 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::{
+    config,
+    crypt::{self, CryptError},
+    input, puzzle, schedule,
+};
+use regex::Regex;
+use std::{
+    collections::BTreeMap,
+    env,
+    fmt::Display,
+    fs::{self, create_dir_all},
+    io,
+    path::Path,
+    time::Duration,
+};
+
+pub const SESSION_ENV: &str = "AOC_SESSION";
+
+const TIMEOUT: Duration = Duration::from_secs(30);
+
+#[derive(Debug)]
+pub enum ClientError {
+    /// neither `AOC_SESSION` nor the session file are present.
+    MissingSession,
+    /// the server rejected the session cookie, it is probably expired.
+    Unauthorized(String),
+    /// the puzzle does not exist or is not unlocked yet.
+    NotFound(String),
+    Status(u16, String),
+    Transport(String),
+    IoError(io::Error),
+    CryptError(CryptError),
+}
+
+impl Display for ClientError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ClientError::MissingSession => write!(
+                f,
+                "no session cookie found, set {} or save it to {}",
+                SESSION_ENV,
+                config::get()
+                    .session_file
+                    .as_ref()
+                    .map_or("a session file".into(), |path| path.display().to_string())
+            ),
+            ClientError::Unauthorized(url) => {
+                write!(f, "{} rejected the session cookie, is it expired?", url)
+            }
+            ClientError::NotFound(url) => {
+                write!(f, "{} was not found, is the puzzle unlocked yet?", url)
+            }
+            ClientError::Status(status, url) => write!(f, "{} returned status {}", url, status),
+            ClientError::Transport(e) => write!(f, "could not reach advent of code: {}", e),
+            ClientError::IoError(e) => write!(f, "could not write output files: {}", e),
+            ClientError::CryptError(e) => write!(f, "could not encrypt input: {}", e),
+        }
+    }
+}
+
+/// A minimal Advent of Code client, authenticated with a session cookie.
+pub struct Client {
+    agent: ureq::Agent,
+    base_url: String,
+    session: String,
+}
+
+impl Client {
+    pub fn new(base_url: &str, session: &str, user_agent: &str) -> Client {
+        Client {
+            agent: ureq::AgentBuilder::new()
+                .timeout(TIMEOUT)
+                .user_agent(user_agent)
+                .build(),
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.trim().to_string(),
+        }
+    }
+
+    /// Creates a client for the configured base URL, with the session from `AOC_SESSION` or the session file.
+    pub fn from_config() -> Result<Client, ClientError> {
+        let config = config::get();
+        let session = match env::var(SESSION_ENV).ok().filter(|s| !s.is_empty()) {
+            Some(session) => session,
+            None => config
+                .session_file
+                .as_ref()
+                .and_then(|path| fs::read_to_string(path).ok())
+                .filter(|session| !session.trim().is_empty())
+                .ok_or(ClientError::MissingSession)?,
+        };
+        Ok(Client::new(&config.base_url, &session, &config.user_agent))
+    }
+
+    /// Returns the puzzle input of a day.
+    pub fn input(&self, year: u16, day: u8) -> Result<String, ClientError> {
+        self.get(&format!("/{}/day/{}/input", year, day))
+    }
+
+    /// Returns the puzzle description of a day as markdown. Contains part two once part one is solved.
+    pub fn puzzle(&self, year: u16, day: u8) -> Result<String, ClientError> {
+        let html = self.get(&format!("/{}/day/{}", year, day))?;
+        Ok(puzzle_markdown(&html))
+    }
+
+    /// Submits an answer and returns the message of the response, e.g. "That's the right answer! [...]".
+    pub fn submit(
+        &self,
+        year: u16,
+        day: u8,
+        part: u8,
+        answer: &str,
+    ) -> Result<String, ClientError> {
+        let url = format!("{}/{}/day/{}/answer", self.base_url, year, day);
+        let response = self
+            .agent
+            .post(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .send_form(&[("level", &part.to_string()), ("answer", answer)]);
+        let html = read_response(response, &url)?;
+        Ok(response_message(&html))
+    }
+
+    /// Returns the number of stars collected for each day of a year.
+    pub fn calendar(&self, year: u16) -> Result<BTreeMap<u8, u8>, ClientError> {
+        let html = self.get(&format!("/{}", year))?;
+        Ok(parse_calendar(&html))
+    }
+
+    fn get(&self, path: &str) -> Result<String, ClientError> {
+        let url = format!("{}{}", self.base_url, path);
+        let response = self
+            .agent
+            .get(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .call();
+        read_response(response, &url)
+    }
+}
+
+fn read_response(
+    response: Result<ureq::Response, ureq::Error>,
+    url: &str,
+) -> Result<String, ClientError> {
+    match response {
+        Ok(response) => response
+            .into_string()
+            .map_err(|e| ClientError::Transport(e.to_string())),
+        // advent of code answers 400 without and 500 with an invalid session cookie.
+        Err(ureq::Error::Status(400 | 401 | 500, _)) => Err(ClientError::Unauthorized(url.into())),
+        Err(ureq::Error::Status(404, _)) => Err(ClientError::NotFound(url.into())),
+        Err(ureq::Error::Status(status, _)) => Err(ClientError::Status(status, url.into())),
+        Err(ureq::Error::Transport(e)) => Err(ClientError::Transport(e.to_string())),
+    }
+}
+
+/// Returns the configured year, or else the year of the latest event.
+pub fn default_year() -> u16 {
+    config::get()
+        .year
+        .unwrap_or_else(|| schedule::latest_year(chrono::Utc::now()))
+}
+
+/// Downloads the input and puzzle description of a day into the data folder.
+/// Without `year`, the configured year is used.
+/// Existing files that differ from the download are kept and a diff is printed, unless `force` is set.
+pub fn download(day: u8, year: Option<u16>, force: bool) -> Result<(), ClientError> {
+    let config = config::get();
+    // check for a key before downloading, so that the input is never left unencrypted.
+    let key = if config.encrypt_inputs {
+        Some(crypt::load_key().map_err(ClientError::CryptError)?)
+    } else {
+        None
+    };
+
+    let client = Client::from_config()?;
+    let event = year.unwrap_or_else(default_year);
+    println!("Downloading day {}, {}...", day, event);
+    let input = client.input(event, day)?;
+    let puzzle = client.puzzle(event, day)?;
+
+    let year = year.or(config.year);
+    let input_path = config.dir("inputs", year).join(format!("{:02}.txt", day));
+    let puzzle_path = config.dir("puzzles", year).join(format!("{:02}.md", day));
+
+    println!("---");
+    for (name, path, content, key) in [
+        ("input", &input_path, &input, key.as_ref()),
+        ("puzzle", &puzzle_path, &puzzle, None),
+    ] {
+        let old = read_existing(path)?;
+        let change = old.as_deref().map(|old| compare(old, content));
+        let diff = |old: &str| {
+            print!("{}", format_diff(old, content, path));
+        };
+        match (&old, change) {
+            (Some(_), Some(Change::Unchanged)) => {
+                println!(
+                    "🎄 Existing {} at \"{}\" is up to date.",
+                    name,
+                    path.display()
+                );
+                continue;
+            }
+            (Some(old), Some(Change::Changed)) if !force => {
+                diff(old);
+                println!(
+                    "Kept existing {} at \"{}\", it differs from the download. Use `--force` to overwrite it.",
+                    name,
+                    path.display()
+                );
+                continue;
+            }
+            (Some(old), Some(_)) => diff(old),
+            _ => {}
+        }
+
+        let content = match key {
+            Some(key) => crypt::encrypt(content, key),
+            None => content.to_string(),
+        };
+        write_file(path, &content)?;
+        println!("🎄 Successfully wrote {} to \"{}\".", name, path.display());
+    }
+    Ok(())
+}
+
+/// Reads a file to compare a download against. Returns None if it is missing or empty, e.g. scaffolded.
+/// Encrypted inputs are decrypted, and compare as changed if that fails.
+fn read_existing(path: &Path) -> Result<Option<String>, ClientError> {
+    let content = match fs::read_to_string(path) {
+        Ok(content) => content,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(None),
+        Err(e) => return Err(ClientError::IoError(e)),
+    };
+    if content.trim().is_empty() {
+        return Ok(None);
+    }
+    if !crypt::is_encrypted(&content) {
+        return Ok(Some(content));
+    }
+    let decrypted = crypt::load_key().and_then(|key| crypt::decrypt(&content, &key));
+    Ok(Some(decrypted.unwrap_or(content)))
+}
+
+/// How a downloaded file differs from the existing one.
+#[derive(Debug, PartialEq, Eq)]
+enum Change {
+    Unchanged,
+    /// the download only adds text, e.g. part two of a puzzle after solving part one.
+    Appended,
+    Changed,
+}
+
+fn compare(old: &str, new: &str) -> Change {
+    let (old, new) = (input::normalize(old), input::normalize(new));
+    if old == new {
+        Change::Unchanged
+    } else if new.starts_with(&old) {
+        Change::Appended
+    } else {
+        Change::Changed
+    }
+}
+
+/// Shows at most this many lines of a diff, inputs can be long.
+const MAX_DIFF_LINES: usize = 40;
+
+/// Formats a unified diff from the existing file to the download.
+fn format_diff(old: &str, new: &str, path: &Path) -> String {
+    let (old, new) = (input::normalize(old) + "\n", input::normalize(new) + "\n");
+    let path = path.display().to_string();
+    let diff = similar::TextDiff::from_lines(&old, &new)
+        .unified_diff()
+        .context_radius(2)
+        .header(&path, "download")
+        .to_string();
+    let lines: Vec<&str> = diff.lines().collect();
+    let mut output = lines
+        .iter()
+        .take(MAX_DIFF_LINES)
+        .map(|line| format!("{}\n", line))
+        .collect::<String>();
+    if lines.len() > MAX_DIFF_LINES {
+        output += &format!("... {} more lines\n", lines.len() - MAX_DIFF_LINES);
+    }
+    output
+}
+
+/// Prints the puzzle description of a day. Uses the downloaded puzzle if present, else fetches it.
+pub fn read(day: u8, year: Option<u16>) -> Result<(), ClientError> {
+    let config = config::get();
+    let puzzle_path = config
+        .dir("puzzles", year.or(config.year))
+        .join(format!("{:02}.md", day));
+    let markdown = match fs::read_to_string(&puzzle_path) {
+        Ok(markdown) => markdown,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => {
+            let client = Client::from_config()?;
+            client.puzzle(year.unwrap_or_else(default_year), day)?
+        }
+        Err(e) => return Err(ClientError::IoError(e)),
+    };
+    print!("{}", puzzle::render(&markdown));
+    Ok(())
+}
+
+fn write_file(path: &Path, content: &str) -> Result<(), ClientError> {
+    if let Some(dir) = path.parent() {
+        create_dir_all(dir).map_err(ClientError::IoError)?;
+    }
+    fs::write(path, content).map_err(ClientError::IoError)
+}
+
+/// Returns the inner HTML of all `<article>` elements, which hold the puzzle text and responses.
+fn articles(html: &str) -> Vec<&str> {
+    let article = Regex::new(r"(?s)<article[^>]*>(.*?)</article>").unwrap();
+    article
+        .captures_iter(html)
+        .filter_map(|captures| captures.get(1))
+        .map(|m| m.as_str())
+        .collect()
+}
+
+fn puzzle_markdown(html: &str) -> String {
+    articles(html)
+        .iter()
+        .map(|article| html2md::parse_html(article).trim().to_string())
+        .collect::<Vec<_>>()
+        .join("\n\n")
+        + "\n"
+}
+
+/// Returns the text of the response to a submission, without markup.
+fn response_message(html: &str) -> String {
+    let tag = Regex::new(r"<[^>]*>").unwrap();
+    let article = articles(html).first().copied().unwrap_or(html);
+    let text = tag
+        .replace_all(article, "")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&amp;", "&");
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Reads the stars per day from the `aria-label` of each day in the calendar, e.g. "Day 3, one star".
+fn parse_calendar(html: &str) -> BTreeMap<u8, u8> {
+    let label = Regex::new(r#"aria-label="Day (\d+)(, (one star|two stars))?""#).unwrap();
+    label
+        .captures_iter(html)
+        .filter_map(|captures| {
+            let day = captures[1].parse().ok()?;
+            let stars = match captures.get(3).map(|m| m.as_str()) {
+                Some("two stars") => 2,
+                Some(_) => 1,
+                None => 0,
+            };
+            Some((day, stars))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{
+        io::{Read, Write},
+        net::TcpListener,
+        thread,
+    };
+
+    /// Serves a single response on a local port. Returns the base URL and a handle to the received request.
+    fn serve_once(status: &str, body: &str) -> (String, thread::JoinHandle<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let response = format!(
+            "HTTP/1.1 {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+            status,
+            body.len(),
+            body
+        );
+        let handle = thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut request = vec![];
+            let mut buffer = [0; 4096];
+            // read the head, then as much body as announced.
+            loop {
+                let read = stream.read(&mut buffer).unwrap();
+                request.extend_from_slice(&buffer[..read]);
+                let text = String::from_utf8_lossy(&request);
+                if let Some(end) = text.find("\r\n\r\n") {
+                    let length = text
+                        .lines()
+                        .find_map(|line| {
+                            line.to_lowercase()
+                                .strip_prefix("content-length: ")?
+                                .parse()
+                                .ok()
+                        })
+                        .unwrap_or(0);
+                    if request.len() >= end + 4 + length {
+                        break;
+                    }
+                }
+                if read == 0 {
+                    break;
+                }
+            }
+            stream.write_all(response.as_bytes()).unwrap();
+            String::from_utf8_lossy(&request).into_owned()
+        });
+        (base_url, handle)
+    }
+
+    #[test]
+    fn test_input() {
+        let (base_url, request) = serve_once("200 OK", "1000\n2000\n");
+        let client = Client::new(&base_url, "abc123\n", config::DEFAULT_USER_AGENT);
+        assert_eq!(client.input(2022, 1).unwrap(), "1000\n2000\n");
+
+        let request = request.join().unwrap();
+        assert!(request.starts_with("GET /2022/day/1/input HTTP/1.1"));
+        assert!(request.contains("session=abc123\r\n"));
+    }
+
+    #[test]
+    fn test_submit() {
+        let body = "<main><article><p>That's the right answer!  You are <span>one gold star</span> closer. \
+            <a href=\"/2022\">[Return to Event Calendar]</a></p></article></main>";
+        let (base_url, request) = serve_once("200 OK", body);
+        let client = Client::new(&base_url, "abc123", config::DEFAULT_USER_AGENT);
+        assert_eq!(
+            client.submit(2022, 7, 2, "24933642").unwrap(),
+            "That's the right answer! You are one gold star closer. [Return to Event Calendar]"
+        );
+
+        let request = request.join().unwrap();
+        assert!(request.starts_with("POST /2022/day/7/answer HTTP/1.1"));
+        assert!(request.ends_with("level=2&answer=24933642"));
+    }
+
+    #[test]
+    fn test_errors() {
+        let (base_url, _) = serve_once("404 Not Found", "");
+        let client = Client::new(&base_url, "abc123", config::DEFAULT_USER_AGENT);
+        assert!(matches!(
+            client.input(2022, 26),
+            Err(ClientError::NotFound(_))
+        ));
+
+        let (base_url, _) = serve_once("500 Internal Server Error", "");
+        let client = Client::new(&base_url, "expired", config::DEFAULT_USER_AGENT);
+        assert!(matches!(
+            client.input(2022, 1),
+            Err(ClientError::Unauthorized(_))
+        ));
+    }
+
+    #[test]
+    fn test_puzzle_markdown() {
+        let html = "<main><article class=\"day-desc\"><h2>--- Day 1: Calorie Counting ---</h2>\
+            <p>Santa's reindeer <em>typically</em> eat food.</p></article>\
+            <p>Your puzzle answer was <code>24000</code>.</p>\
+            <article class=\"day-desc\"><h2 id=\"part2\">--- Part Two ---</h2><p>By the time you calculate...</p></article></main>";
+        let markdown = puzzle_markdown(html);
+        assert!(markdown.contains("Day 1: Calorie Counting"));
+        assert!(markdown.contains("*typically*"));
+        assert!(markdown.contains("Part Two"));
+        assert!(!markdown.contains("Your puzzle answer"));
+    }
+
+    #[test]
+    fn test_parse_calendar() {
+        let html = r#"<a aria-label="Day 1, two stars" href="/2022/day/1">
+            <a aria-label="Day 2, one star" href="/2022/day/2"><a aria-label="Day 3" href="/2022/day/3">"#;
+        let stars = parse_calendar(html);
+        assert_eq!(stars, BTreeMap::from([(1, 2), (2, 1), (3, 0)]));
+    }
+
+    #[test]
+    fn test_compare() {
+        assert_eq!(compare("1\n2\n", "1\r\n2"), Change::Unchanged);
+        assert_eq!(
+            compare("Part one", "Part one\n\nPart two"),
+            Change::Appended
+        );
+        assert_eq!(compare("1\n2 # note\n", "1\n2\n"), Change::Changed);
+    }
+
+    #[test]
+    fn test_format_diff() {
+        let diff = format_diff("1\n2 # note\n3", "1\n2\n3\n", Path::new("07.txt"));
+        assert_eq!(
+            diff,
+            "--- 07.txt\n+++ download\n@@ -1,3 +1,3 @@\n 1\n-2 # note\n+2\n 3\n"
+        );
+
+        let old = "1\n".repeat(100);
+        let diff = format_diff(&old, "2", Path::new("07.txt"));
+        assert_eq!(diff.lines().count(), MAX_DIFF_LINES + 1);
+        assert!(diff.ends_with("... 64 more lines\n"));
+    }
+}
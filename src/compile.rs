@@ -0,0 +1,293 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::config;
+use serde::Deserialize;
+use std::{
+    collections::BTreeMap,
+    env, fs, io,
+    path::PathBuf,
+    process::{Command, Stdio},
+};
+
+/// The binary run by the workers, built without the solution modules that do not compile.
+pub struct WorkerBuild {
+    pub exe: PathBuf,
+    /// the compiler errors of each module that was left out, e.g. of `day07` or `y2021::day07`.
+    pub failed: BTreeMap<String, String>,
+}
+
+impl WorkerBuild {
+    /// Returns the compiler errors of the days of `year`'s registry that were left out.
+    pub fn failed_days(&self, year: Option<u16>) -> BTreeMap<u8, String> {
+        let prefix = year.map_or(String::new(), |year| format!("y{}::", year));
+        self.failed
+            .iter()
+            .filter_map(|(module, errors)| {
+                let name = module.strip_prefix(&prefix)?;
+                let day = name.strip_prefix("day")?.parse().ok()?;
+                Some((day, errors.clone()))
+            })
+            .collect()
+    }
+}
+
+/// A JSON line printed by `cargo build --message-format=json`.
+#[derive(Debug, Deserialize)]
+struct CargoMessage {
+    reason: String,
+    message: Option<Diagnostic>,
+    target: Option<Target>,
+    executable: Option<PathBuf>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Target {
+    name: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct Diagnostic {
+    level: String,
+    rendered: Option<String>,
+    spans: Vec<Span>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Span {
+    file_name: String,
+    is_primary: bool,
+}
+
+/// Builds the `aoc` binary with the solutions for the workers into `target/workers`.
+/// If the build fails with errors in solution modules, these modules are left out and the build is repeated,
+/// see `build.rs`. Errors outside the solution modules fail the build of all days.
+pub fn build_workers() -> Result<WorkerBuild, String> {
+    let mut failed = BTreeMap::new();
+    loop {
+        let output = cargo_build(&failed)?;
+        let messages = parse_messages(&output);
+        if let Some(exe) = messages.iter().find_map(executable) {
+            return Ok(WorkerBuild { exe, failed });
+        }
+
+        let errors = errors_by_module(&messages)?;
+        let before = failed.len();
+        for (module, error) in errors {
+            failed.entry(module).or_insert(error);
+        }
+        if failed.len() == before {
+            return Err(all_errors(&messages));
+        }
+    }
+}
+
+/// Runs `cargo build` for the worker binary without the modules in `skip`. Returns the JSON messages.
+fn cargo_build(skip: &BTreeMap<String, String>) -> Result<String, String> {
+    let config = config::get();
+    let target_dir = env::var_os("CARGO_TARGET_DIR")
+        .map_or_else(|| config.root.join("target"), PathBuf::from)
+        .join("workers");
+
+    let mut command = Command::new(env::var_os("CARGO").unwrap_or_else(|| "cargo".into()));
+    command
+        .args(["build", "--bin", "aoc"])
+        .arg("--message-format=json-diagnostic-rendered-ansi")
+        .arg("--manifest-path")
+        .arg(config.root.join("Cargo.toml"))
+        .arg("--target-dir")
+        .arg(target_dir)
+        .env(
+            "AOC_SKIP",
+            skip.keys().cloned().collect::<Vec<_>>().join(","),
+        )
+        // cargo reports its progress on stderr.
+        .stderr(Stdio::inherit());
+    if !cfg!(debug_assertions) {
+        command.arg("--release");
+    }
+    if cfg!(feature = "count-allocations") {
+        command.args(["--features", "count-allocations"]);
+    }
+
+    let output = command
+        .output()
+        .map_err(|e| format!("failed to run cargo to build the workers: {}", e))?;
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+fn parse_messages(output: &str) -> Vec<CargoMessage> {
+    output
+        .lines()
+        .filter_map(|line| serde_json::from_str(line).ok())
+        .collect()
+}
+
+fn executable(message: &CargoMessage) -> Option<PathBuf> {
+    match &message.target {
+        Some(target) if message.reason == "compiler-artifact" && target.name == "aoc" => {
+            message.executable.clone()
+        }
+        _ => None,
+    }
+}
+
+fn errors(messages: &[CargoMessage]) -> impl Iterator<Item = &Diagnostic> {
+    messages
+        .iter()
+        .filter(|message| message.reason == "compiler-message")
+        .filter_map(|message| message.message.as_ref())
+        .filter(|diagnostic| diagnostic.level == "error")
+}
+
+/// Groups the compiler errors by the solution module they point to.
+/// Errors without a location, like "aborting due to 2 previous errors", are ignored.
+/// Returns all errors if any of them points to a file outside the solution modules.
+fn errors_by_module(messages: &[CargoMessage]) -> Result<BTreeMap<String, String>, String> {
+    let mut modules: BTreeMap<String, String> = BTreeMap::new();
+    for diagnostic in errors(messages) {
+        let Some(span) = diagnostic.spans.iter().find(|span| span.is_primary) else {
+            continue;
+        };
+        let Some(module) = module_of(&span.file_name) else {
+            return Err(all_errors(messages));
+        };
+        *modules.entry(module).or_default() += diagnostic.rendered.as_deref().unwrap_or("");
+    }
+    Ok(modules)
+}
+
+fn all_errors(messages: &[CargoMessage]) -> String {
+    let rendered: String = errors(messages)
+        .filter_map(|diagnostic| diagnostic.rendered.as_deref())
+        .collect();
+    if rendered.is_empty() {
+        "failed to build the workers.".to_string()
+    } else {
+        rendered
+    }
+}
+
+/// Returns the solution module of a source file, e.g. `y2021::day07` for `src/solutions/y2021/day07.rs`.
+fn module_of(file: &str) -> Option<String> {
+    let file = file.replace('\\', "/");
+    let module = file.strip_prefix("src/solutions/")?.strip_suffix(".rs")?;
+    let name = module.rsplit('/').next()?;
+    name.strip_prefix("day")?.parse::<u8>().ok()?;
+    Some(module.replace('/', "::"))
+}
+
+/// Returns the year whose registry `solutions::registry` returns, None for the days scaffolded without a year.
+pub fn registry_year() -> Option<u16> {
+    let config = config::get();
+    config
+        .year
+        .filter(|&year| config.registry_path(Some(year)).exists())
+}
+
+/// Returns the days declared in the registry of `year`, read from its source.
+pub fn registered_days(year: Option<u16>) -> io::Result<Vec<u8>> {
+    let registry = fs::read_to_string(config::get().registry_path(year))?;
+    Ok(registry
+        .lines()
+        .filter_map(|line| {
+            line.strip_prefix("pub mod day")?
+                .strip_suffix(';')?
+                .parse()
+                .ok()
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn error(file: Option<&str>, rendered: &str) -> String {
+        let spans = match file {
+            Some(file) => format!(r#"[{{"file_name":"{}","is_primary":true}}]"#, file),
+            None => "[]".to_string(),
+        };
+        format!(
+            r#"{{"reason":"compiler-message","message":{{"level":"error","rendered":"{}","spans":{}}}}}"#,
+            rendered, spans
+        )
+    }
+
+    #[test]
+    fn test_module_of() {
+        assert_eq!(
+            module_of("src/solutions/day07.rs"),
+            Some("day07".to_string())
+        );
+        assert_eq!(
+            module_of("src/solutions/y2021/day07.rs"),
+            Some("y2021::day07".to_string())
+        );
+        assert_eq!(module_of("src/solutions/mod.rs"), None);
+        assert_eq!(module_of("src/helpers.rs"), None);
+    }
+
+    #[test]
+    fn test_errors_by_module() {
+        let output = [
+            error(Some("src/solutions/day07.rs"), "first"),
+            error(Some("src/solutions/day07.rs"), "second"),
+            error(Some("src/solutions/day16.rs"), "third"),
+            error(None, "aborting"),
+        ]
+        .join("\n");
+        let modules = errors_by_module(&parse_messages(&output)).unwrap();
+        assert_eq!(
+            modules.into_iter().collect::<Vec<_>>(),
+            vec![
+                ("day07".to_string(), "firstsecond".to_string()),
+                ("day16".to_string(), "third".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_errors_outside_solutions() {
+        let output = [
+            error(Some("src/solutions/day07.rs"), "day"),
+            error(Some("src/helpers.rs"), "helper"),
+        ]
+        .join("\n");
+        assert_eq!(
+            errors_by_module(&parse_messages(&output)),
+            Err("dayhelper".to_string())
+        );
+    }
+
+    #[test]
+    fn test_executable() {
+        let output =
+            r#"{"reason":"compiler-artifact","target":{"name":"aoc"},"executable":"/target/aoc"}"#;
+        let messages = parse_messages(output);
+        assert_eq!(
+            messages.iter().find_map(executable),
+            Some(PathBuf::from("/target/aoc"))
+        );
+    }
+
+    #[test]
+    fn test_failed_days() {
+        let build = WorkerBuild {
+            exe: PathBuf::new(),
+            failed: BTreeMap::from([
+                ("day07".to_string(), "a".to_string()),
+                ("y2021::day03".to_string(), "b".to_string()),
+            ]),
+        };
+        assert_eq!(
+            build.failed_days(None),
+            BTreeMap::from([(7, "a".to_string())])
+        );
+        assert_eq!(
+            build.failed_days(Some(2021)),
+            BTreeMap::from([(3, "b".to_string())])
+        );
+    }
+}
@@ -0,0 +1,313 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use serde::Deserialize;
+use std::{
+    env,
+    fmt::Display,
+    fs, io,
+    path::{Path, PathBuf},
+    process,
+    sync::OnceLock,
+};
+
+pub const CONFIG_PATH: &str = "aoc.toml";
+pub const DEFAULT_DATA_DIR: &str = "src";
+pub const DEFAULT_KEY_FILE: &str = ".aoc-key";
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+/// The session file of aoc-cli, relative to the home directory.
+pub const DEFAULT_SESSION_FILE: &str = ".adventofcode.session";
+/// Sent with every request to Advent of Code, unless `user_agent` is set.
+pub const DEFAULT_USER_AGENT: &str =
+    concat!(env!("CARGO_PKG_NAME"), "/", env!("CARGO_PKG_VERSION"));
+
+/// Settings in `aoc.toml`, all optional.
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ConfigFile {
+    data_dir: Option<PathBuf>,
+    year: Option<u16>,
+    #[serde(default)]
+    encrypt_inputs: bool,
+    key_file: Option<PathBuf>,
+    base_url: Option<String>,
+    session_file: Option<PathBuf>,
+    user_agent: Option<String>,
+}
+
+/// Where puzzle data is stored and how to reach Advent of Code. Read from `aoc.toml`, environment
+/// variables override it: `AOC_DATA_DIR` sets `data_dir`, `AOC_YEAR` sets `year` and `AOC_BASE_URL` sets `base_url`.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Config {
+    /// the repository root, see `find_root`. Solutions, templates and the readme are found relative to it.
+    pub root: PathBuf,
+    /// the folder containing `inputs/`, `examples/`, `puzzles/` and `answers/`.
+    pub data_dir: PathBuf,
+    /// the year of the solutions in this crate. If set, data is stored in `<data_dir>/<year>/`.
+    pub year: Option<u16>,
+    /// encrypt downloaded inputs, see `crypt`.
+    pub encrypt_inputs: bool,
+    /// the key used to encrypt and decrypt inputs, unless `AOC_INPUT_KEY` is set.
+    pub key_file: PathBuf,
+    /// the Advent of Code server, can be changed to test against a local server.
+    pub base_url: String,
+    /// the file containing the session cookie, unless `AOC_SESSION` is set.
+    pub session_file: Option<PathBuf>,
+    /// sent with every request, so that Advent of Code can tell who to contact about the traffic.
+    pub user_agent: String,
+}
+
+#[derive(Debug)]
+pub enum ConfigError {
+    IoError(io::Error),
+    ParseError(toml::de::Error),
+    InvalidYear(String),
+}
+
+impl Display for ConfigError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ConfigError::IoError(e) => write!(f, "could not read {}: {}", CONFIG_PATH, e),
+            ConfigError::ParseError(e) => write!(f, "could not parse {}: {}", CONFIG_PATH, e),
+            ConfigError::InvalidYear(year) => write!(f, "AOC_YEAR is not a valid year: {}", year),
+        }
+    }
+}
+
+impl Config {
+    /// Loads `aoc.toml`, if present, and applies the environment. See `find_root`.
+    pub fn load() -> Result<Config, ConfigError> {
+        let root = find_root(&env::current_dir().map_err(ConfigError::IoError)?);
+        let file = match fs::read_to_string(root.join(CONFIG_PATH)) {
+            Ok(content) => toml::from_str(&content).map_err(ConfigError::ParseError)?,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => ConfigFile::default(),
+            Err(e) => return Err(ConfigError::IoError(e)),
+        };
+        Config::resolve(file, &root, |name| {
+            env::var(name).ok().filter(|value| !value.is_empty())
+        })
+    }
+
+    /// Applies the environment variables returned by `var` to the settings of `file`.
+    fn resolve(
+        file: ConfigFile,
+        root: &Path,
+        var: impl Fn(&str) -> Option<String>,
+    ) -> Result<Config, ConfigError> {
+        let year = match var("AOC_YEAR") {
+            Some(year) => Some(year.parse().map_err(|_| ConfigError::InvalidYear(year))?),
+            None => file.year,
+        };
+        let data_dir = var("AOC_DATA_DIR")
+            .map(PathBuf::from)
+            .or(file.data_dir)
+            .unwrap_or_else(|| PathBuf::from(DEFAULT_DATA_DIR));
+
+        let key_file = file
+            .key_file
+            .unwrap_or_else(|| PathBuf::from(DEFAULT_KEY_FILE));
+
+        let base_url = var("AOC_BASE_URL")
+            .or(file.base_url)
+            .unwrap_or_else(|| DEFAULT_BASE_URL.to_string());
+        let session_file = match file.session_file {
+            Some(path) => Some(root.join(path)),
+            None => var("HOME")
+                .or_else(|| var("USERPROFILE"))
+                .map(|home| Path::new(&home).join(DEFAULT_SESSION_FILE)),
+        };
+
+        Ok(Config {
+            root: root.to_path_buf(),
+            data_dir: root.join(data_dir),
+            year,
+            encrypt_inputs: file.encrypt_inputs,
+            key_file: root.join(key_file),
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session_file,
+            user_agent: file
+                .user_agent
+                .unwrap_or_else(|| DEFAULT_USER_AGENT.to_string()),
+        })
+    }
+
+    /// Returns the data folder of `year`, e.g. `src/inputs` or `src/2021/inputs`.
+    pub fn dir(&self, folder: &str, year: Option<u16>) -> PathBuf {
+        match year {
+            Some(year) => self.data_dir.join(year.to_string()).join(folder),
+            None => self.data_dir.join(folder),
+        }
+    }
+
+    /// Returns the path of a data file of the configured year, e.g. `src/inputs/07.txt`.
+    pub fn path(&self, folder: &str, day: u8, extension: &str) -> PathBuf {
+        self.year_path(folder, self.year, day, extension)
+    }
+
+    /// Returns the path of a data file of `year`, e.g. `src/2021/inputs/07.txt`.
+    pub fn year_path(&self, folder: &str, year: Option<u16>, day: u8, extension: &str) -> PathBuf {
+        self.dir(folder, year)
+            .join(format!("{:02}.{}", day, extension))
+    }
+
+    /// Returns the solution module of a day, e.g. `src/solutions/day07.rs` or `src/solutions/y2021/day07.rs`.
+    pub fn module_path(&self, day: u8, year: Option<u16>) -> PathBuf {
+        self.root
+            .join(solutions_dir(year))
+            .join(format!("day{:02}.rs", day))
+    }
+
+    /// Returns the registry listing the solutions of `year`, e.g. `src/solutions/y2021/mod.rs`.
+    pub fn registry_path(&self, year: Option<u16>) -> PathBuf {
+        self.root.join(solutions_dir(year)).join("mod.rs")
+    }
+
+    /// Returns the binary of a day, e.g. `src/bin/07.rs` or `src/bin/2021-07.rs`.
+    pub fn bin_path(&self, day: u8, year: Option<u16>) -> PathBuf {
+        self.root
+            .join("src")
+            .join("bin")
+            .join(format!("{}.rs", bin_name(day, year)))
+    }
+}
+
+/// Returns the folder of the solutions of `year` relative to the root. Solutions without a year
+/// live in `src/solutions`, the ones of a year in `src/solutions/y<year>`.
+pub fn solutions_dir(year: Option<u16>) -> String {
+    match year {
+        Some(year) => format!("src/solutions/y{}", year),
+        None => "src/solutions".to_string(),
+    }
+}
+
+/// Returns the name of the binary of a day, which is passed to `cargo solve`, e.g. `07` or `2021-07`.
+pub fn bin_name(day: u8, year: Option<u16>) -> String {
+    match year {
+        Some(year) => format!("{}-{:02}", year, day),
+        None => format!("{:02}", day),
+    }
+}
+
+/// Returns the repository root: the closest folder containing `aoc.toml`, starting at `dir`.
+/// Falls back to the folder this crate was built from, so binaries also work outside the repository.
+fn find_root(dir: &Path) -> PathBuf {
+    dir.ancestors()
+        .find(|dir| dir.join(CONFIG_PATH).is_file())
+        .map_or_else(
+            || PathBuf::from(env!("CARGO_MANIFEST_DIR")),
+            Path::to_path_buf,
+        )
+}
+
+/// Returns the configuration of this run. Exits if it cannot be loaded.
+pub fn get() -> &'static Config {
+    static CONFIG: OnceLock<Config> = OnceLock::new();
+    CONFIG.get_or_init(|| {
+        Config::load().unwrap_or_else(|e| {
+            eprintln!("{}", e);
+            process::exit(1);
+        })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    fn parse(content: &str) -> ConfigFile {
+        toml::from_str(content).unwrap()
+    }
+
+    fn resolve(content: &str, vars: &[(&str, &str)]) -> Result<Config, ConfigError> {
+        let vars: HashMap<&str, &str> = vars.iter().copied().collect();
+        Config::resolve(parse(content), Path::new("/repo"), |name| {
+            vars.get(name).map(|value| value.to_string())
+        })
+    }
+
+    #[test]
+    fn test_resolve() {
+        let config = resolve("", &[("HOME", "/home/me")]).unwrap();
+        assert_eq!(config.data_dir, Path::new("/repo/src"));
+        assert_eq!(config.key_file, Path::new("/repo/.aoc-key"));
+        assert!(!config.encrypt_inputs);
+        assert_eq!(config.base_url, DEFAULT_BASE_URL);
+        assert_eq!(config.user_agent, DEFAULT_USER_AGENT);
+        assert_eq!(
+            config.session_file.as_deref(),
+            Some(Path::new("/home/me/.adventofcode.session"))
+        );
+        assert_eq!(
+            config.path("inputs", 7, "txt"),
+            Path::new("/repo/src/inputs/07.txt")
+        );
+
+        let config = resolve("data_dir = \"data\"\nyear = 2021\n", &[]).unwrap();
+        assert_eq!(
+            config.path("puzzles", 7, "md"),
+            Path::new("/repo/data/2021/puzzles/07.md")
+        );
+        assert_eq!(
+            config.dir("inputs", Some(2022)),
+            Path::new("/repo/data/2022/inputs")
+        );
+        assert_eq!(config.session_file, None);
+        assert_eq!(
+            config.module_path(7, Some(2021)),
+            Path::new("/repo/src/solutions/y2021/day07.rs")
+        );
+        assert_eq!(
+            config.registry_path(None),
+            Path::new("/repo/src/solutions/mod.rs")
+        );
+        assert_eq!(
+            config.bin_path(7, Some(2021)),
+            Path::new("/repo/src/bin/2021-07.rs")
+        );
+        assert_eq!(config.bin_path(7, None), Path::new("/repo/src/bin/07.rs"));
+
+        let config = resolve(
+            "encrypt_inputs = true\nkey_file = \"/keys/aoc\"\nsession_file = \"session\"\nuser_agent = \"me@example.com\"\n",
+            &[],
+        )
+        .unwrap();
+        assert_eq!(config.user_agent, "me@example.com");
+        assert!(config.encrypt_inputs);
+        assert_eq!(config.key_file, Path::new("/keys/aoc"));
+        assert_eq!(
+            config.session_file.as_deref(),
+            Some(Path::new("/repo/session"))
+        );
+
+        let vars = [
+            ("AOC_DATA_DIR", "/aoc"),
+            ("AOC_YEAR", "2020"),
+            ("AOC_BASE_URL", "http://localhost:8080/"),
+        ];
+        let config = resolve("year = 2021\n", &vars).unwrap();
+        assert_eq!(
+            config.path("inputs", 1, "txt"),
+            Path::new("/aoc/2020/inputs/01.txt")
+        );
+        assert_eq!(config.base_url, "http://localhost:8080");
+    }
+
+    #[test]
+    fn test_find_root() {
+        let manifest_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+        assert_eq!(
+            find_root(&manifest_dir.join("src").join("bin")),
+            manifest_dir
+        );
+        assert_eq!(find_root(Path::new("/")), manifest_dir);
+    }
+
+    #[test]
+    fn test_resolve_invalid() {
+        assert!(toml::from_str::<ConfigFile>("data = \"src\"").is_err());
+        let result = resolve("", &[("AOC_YEAR", "next")]);
+        assert!(matches!(result, Err(ConfigError::InvalidYear(_))));
+    }
+}
@@ -0,0 +1,159 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::config;
+use base64::{engine::general_purpose::STANDARD, Engine};
+use chacha20poly1305::{
+    aead::{Aead, KeyInit, OsRng},
+    AeadCore, ChaCha20Poly1305, Key, Nonce,
+};
+use std::{env, fmt::Display, fs, io, path::Path};
+
+/// First line of an encrypted input. The second line holds the base64 encoded nonce and ciphertext.
+pub const HEADER: &str = "aoc-encrypted-input v1";
+pub const KEY_ENV: &str = "AOC_INPUT_KEY";
+
+const NONCE_LENGTH: usize = 12;
+
+#[derive(Debug)]
+pub enum CryptError {
+    /// neither `AOC_INPUT_KEY` nor the key file are present.
+    MissingKey,
+    InvalidKey,
+    IoError(io::Error),
+    InvalidFormat,
+    /// the input was encrypted with another key, or was modified.
+    DecryptError,
+}
+
+impl Display for CryptError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CryptError::MissingKey => write!(
+                f,
+                "no input key found, set {} or create {} with `cargo crypt keygen`",
+                KEY_ENV,
+                config::get().key_file.display()
+            ),
+            CryptError::InvalidKey => write!(f, "input key is not a base64 encoded 32 byte key"),
+            CryptError::IoError(e) => write!(f, "could not read input key: {}", e),
+            CryptError::InvalidFormat => write!(f, "encrypted input is malformed"),
+            CryptError::DecryptError => {
+                write!(
+                    f,
+                    "could not decrypt input, was it encrypted with another key?"
+                )
+            }
+        }
+    }
+}
+
+/// Returns true if `content` was written by `encrypt`.
+pub fn is_encrypted(content: &str) -> bool {
+    content.starts_with(HEADER)
+}
+
+/// Returns a new random key, base64 encoded.
+pub fn generate_key() -> String {
+    STANDARD.encode(ChaCha20Poly1305::generate_key(&mut OsRng))
+}
+
+pub fn parse_key(encoded: &str) -> Result<Key, CryptError> {
+    let bytes = STANDARD
+        .decode(encoded.trim())
+        .map_err(|_| CryptError::InvalidKey)?;
+    if bytes.len() != 32 {
+        return Err(CryptError::InvalidKey);
+    }
+    Ok(*Key::from_slice(&bytes))
+}
+
+/// Loads the key from `AOC_INPUT_KEY`, or else from the key file.
+pub fn load_key() -> Result<Key, CryptError> {
+    if let Some(key) = env::var(KEY_ENV).ok().filter(|key| !key.is_empty()) {
+        return parse_key(&key);
+    }
+    read_key_file(&config::get().key_file)
+}
+
+fn read_key_file(path: &Path) -> Result<Key, CryptError> {
+    match fs::read_to_string(path) {
+        Ok(content) => parse_key(&content),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Err(CryptError::MissingKey),
+        Err(e) => Err(CryptError::IoError(e)),
+    }
+}
+
+pub fn encrypt(plaintext: &str, key: &Key) -> String {
+    let cipher = ChaCha20Poly1305::new(key);
+    let nonce = ChaCha20Poly1305::generate_nonce(&mut OsRng);
+    let ciphertext = cipher
+        .encrypt(&nonce, plaintext.as_bytes())
+        .expect("encryption does not fail for inputs of this size");
+
+    let mut payload = nonce.to_vec();
+    payload.extend(ciphertext);
+    format!("{}\n{}\n", HEADER, STANDARD.encode(payload))
+}
+
+pub fn decrypt(content: &str, key: &Key) -> Result<String, CryptError> {
+    let payload = content
+        .strip_prefix(HEADER)
+        .map(str::trim)
+        .ok_or(CryptError::InvalidFormat)?;
+    let payload = STANDARD
+        .decode(payload)
+        .map_err(|_| CryptError::InvalidFormat)?;
+    if payload.len() < NONCE_LENGTH {
+        return Err(CryptError::InvalidFormat);
+    }
+
+    let (nonce, ciphertext) = payload.split_at(NONCE_LENGTH);
+    let plaintext = ChaCha20Poly1305::new(key)
+        .decrypt(Nonce::from_slice(nonce), ciphertext)
+        .map_err(|_| CryptError::DecryptError)?;
+    String::from_utf8(plaintext).map_err(|_| CryptError::InvalidFormat)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip() {
+        let key = parse_key(&generate_key()).unwrap();
+        let encrypted = encrypt("1000\n2000\n\n3000\n", &key);
+        assert!(is_encrypted(&encrypted));
+        assert!(!encrypted.contains("1000"));
+        assert_eq!(decrypt(&encrypted, &key).unwrap(), "1000\n2000\n\n3000\n");
+
+        // the nonce is random, so encrypting twice gives different files.
+        assert_ne!(encrypt("1000", &key), encrypt("1000", &key));
+    }
+
+    #[test]
+    fn test_wrong_key() {
+        let key = parse_key(&generate_key()).unwrap();
+        let other = parse_key(&generate_key()).unwrap();
+        let encrypted = encrypt("A Y", &key);
+        assert!(matches!(
+            decrypt(&encrypted, &other),
+            Err(CryptError::DecryptError)
+        ));
+        assert!(matches!(
+            decrypt(&format!("{}\nnot base64!\n", HEADER), &key),
+            Err(CryptError::InvalidFormat)
+        ));
+    }
+
+    #[test]
+    fn test_parse_key() {
+        assert!(parse_key(&format!("{}\n", generate_key())).is_ok());
+        assert!(matches!(parse_key("c2hvcnQ="), Err(CryptError::InvalidKey)));
+        assert!(matches!(
+            read_key_file(Path::new("does/not/exist")),
+            Err(CryptError::MissingKey)
+        ));
+    }
+}
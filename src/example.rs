@@ -0,0 +1,255 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::{config, input, solution::Solution};
+use regex::Regex;
+use serde::Deserialize;
+use std::{collections::BTreeMap, fmt::Display, fs};
+
+/// The example input and expected answers found in a puzzle description.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Extract {
+    pub example: Option<String>,
+    pub part_one: Option<String>,
+    pub part_two: Option<String>,
+}
+
+/// Reads the example and answers from the puzzle markdown written by `client::download`.
+/// The example is the first code block introduced by a sentence mentioning an example.
+/// The answer of a part is the last highlighted code in its text, e.g. `` `*24000*` ``.
+pub fn extract(markdown: &str) -> Extract {
+    let lines: Vec<&str> = markdown.lines().collect();
+    let part_two = lines
+        .iter()
+        .position(|line| line.contains("--- Part Two ---"))
+        .unwrap_or(lines.len());
+    let (one, two) = lines.split_at(part_two);
+
+    Extract {
+        example: find_example(one),
+        part_one: find_answer(one),
+        part_two: find_answer(two),
+    }
+}
+
+fn find_example(lines: &[&str]) -> Option<String> {
+    let mut blocks = vec![];
+    let mut block: Option<Vec<&str>> = None;
+    let mut intro = "";
+
+    for line in lines {
+        if line.trim_start().starts_with("```") {
+            match block.take() {
+                Some(code) => blocks.push((intro, code.join("\n").trim_end().to_string())),
+                None => block = Some(vec![]),
+            }
+        } else if let Some(code) = block.as_mut() {
+            code.push(line);
+        } else if !line.trim().is_empty() {
+            intro = line;
+        }
+    }
+
+    let blocks: Vec<_> = blocks
+        .into_iter()
+        .filter(|(_, code)| !code.is_empty())
+        .collect();
+    blocks
+        .iter()
+        .find(|(intro, _)| intro.to_lowercase().contains("example"))
+        .or(blocks.first())
+        .map(|(_, code)| code.clone())
+}
+
+fn find_answer(lines: &[&str]) -> Option<String> {
+    let highlight = Regex::new(r"`\*([^`*]+)\*`|\*`([^`*]+)`\*").unwrap();
+    let mut in_code = false;
+    let mut answer = None;
+
+    for line in lines {
+        if line.trim_start().starts_with("```") {
+            in_code = !in_code;
+        } else if !in_code {
+            if let Some(captures) = highlight.captures_iter(line).last() {
+                let value = captures.get(1).or(captures.get(2)).unwrap();
+                answer = Some(value.as_str().trim().to_string());
+            }
+        }
+    }
+
+    answer
+}
+
+/// Replaces the `None` assertion of a part in a scaffolded test with the expected answer.
+/// Assertions that were already edited are kept.
+pub fn fill_test(module: &str, part: u8, answer: &str) -> String {
+    let function = match part {
+        1 => "part_one",
+        _ => "part_two",
+    };
+    let literal = match answer.parse::<i64>() {
+        Ok(number) => number.to_string(),
+        Err(_) => format!("{:?}.to_string()", answer),
+    };
+    module.replacen(
+        &format!("assert_eq!({}(&input), None);", function),
+        &format!("assert_eq!({}(&input), Some({}));", function, literal),
+        1,
+    )
+}
+
+/// The start of the attribute that ignores a scaffolded test until the example is filled in.
+const IGNORE_WITHOUT_EXAMPLE: &str = "#[ignore = \"needs the example";
+
+/// Removes the attributes that ignore scaffolded tests without an example. Other ignored tests are kept.
+pub fn enable_tests(module: &str) -> String {
+    module
+        .split_inclusive('\n')
+        .filter(|line| !line.trim_start().starts_with(IGNORE_WITHOUT_EXAMPLE))
+        .collect()
+}
+
+/// An expected answer in an example manifest, written as a number or a string.
+#[derive(Debug, PartialEq, Eq, Deserialize)]
+#[serde(untagged)]
+pub enum Answer {
+    Number(i64),
+    Text(String),
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answer::Number(number) => write!(f, "{}", number),
+            Answer::Text(text) => write!(f, "{}", text),
+        }
+    }
+}
+
+/// The expected answers of one named example. Parts without an answer are not checked.
+#[derive(Debug, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Expected {
+    pub part_one: Option<Answer>,
+    pub part_two: Option<Answer>,
+}
+
+/// The manifest `examples/NN.toml` of a day, with one table per example file `examples/NN-<name>.txt`.
+pub type Manifest = BTreeMap<String, Expected>;
+
+/// Checks a named example of a solution against its manifest entry. Panics on mismatch, so it can back a test.
+/// Use it through `example_tests!`.
+pub fn check<S: Solution>(name: &str) {
+    let day = S::metadata().day;
+    let config = config::get();
+    let manifest_path = config.path("examples", day, "toml");
+    let manifest = fs::read_to_string(&manifest_path)
+        .unwrap_or_else(|e| panic!("could not read {}: {}", manifest_path.display(), e));
+    let manifest: Manifest = toml::from_str(&manifest)
+        .unwrap_or_else(|e| panic!("could not parse {}: {}", manifest_path.display(), e));
+    let expected = manifest
+        .get(name)
+        .unwrap_or_else(|| panic!("no entry [{}] in {}", name, manifest_path.display()));
+    assert!(
+        expected.part_one.is_some() || expected.part_two.is_some(),
+        "entry [{}] has no expected answers",
+        name
+    );
+
+    let path = config
+        .dir("examples", config.year)
+        .join(format!("{:02}-{}.txt", day, name));
+    let input = input::read(&path, day).unwrap_or_else(|e| panic!("{}", e));
+    let input = S::parse(&input);
+    if let Some(answer) = &expected.part_one {
+        let result = S::part_one(&input).map(|result| result.to_string());
+        assert_eq!(
+            result,
+            Some(answer.to_string()),
+            "part one of example {}",
+            name
+        );
+    }
+    if let Some(answer) = &expected.part_two {
+        let result = S::part_two(&input).map(|result| result.to_string());
+        assert_eq!(
+            result,
+            Some(answer.to_string()),
+            "part two of example {}",
+            name
+        );
+    }
+}
+
+/// Generates one `#[test]` per named example of a solution, checked against the manifest `examples/NN.toml`.
+///
+/// ```ignore
+/// crate::example_tests!(Day06: second, third);
+/// ```
+#[macro_export]
+macro_rules! example_tests {
+    ($solution:ty: $($name:ident),+ $(,)?) => {
+        $(
+            #[test]
+            fn $name() {
+                $crate::example::check::<$solution>(stringify!($name));
+            }
+        )+
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PUZZLE: &str = "\\--- Day 1: Calorie Counting ---\n----------\n\nThe Elves write down `1000` calories:\n\n```\nabc\n```\n\nFor example, suppose the Elves finished writing:\n\n```\n1000\n2000\n\n3000\n\n```\n\n* The first Elf is carrying `6000` Calories.\n\nIn the example above, this is `*24000*` (carried by the *fourth* Elf).\n\nYour puzzle answer was `71124`.\n\n\\--- Part Two ---\n----------\n\nThe sum is `*45000*`.\n";
+
+    #[test]
+    fn test_extract() {
+        assert_eq!(
+            extract(PUZZLE),
+            Extract {
+                example: Some("1000\n2000\n\n3000".into()),
+                part_one: Some("24000".into()),
+                part_two: Some("45000".into()),
+            }
+        );
+        assert_eq!(extract("No code here.\n"), Extract::default());
+    }
+
+    #[test]
+    fn test_fill_test() {
+        let module = "assert_eq!(part_one(&input), None);\nassert_eq!(part_two(&input), None);\n";
+        let module = fill_test(module, 1, "24000");
+        let module = fill_test(&module, 2, "ABC");
+        assert_eq!(
+            module,
+            "assert_eq!(part_one(&input), Some(24000));\nassert_eq!(part_two(&input), Some(\"ABC\".to_string()));\n"
+        );
+    }
+
+    #[test]
+    fn test_enable_tests() {
+        let module = "    #[test]\n    #[ignore = \"needs the example, run `cargo example 7`\"]\n    fn test_part_one() {}\n    #[test]\n    #[ignore]\n    fn slow() {}\n";
+        assert_eq!(
+            enable_tests(module),
+            "    #[test]\n    fn test_part_one() {}\n    #[test]\n    #[ignore]\n    fn slow() {}\n"
+        );
+    }
+
+    #[test]
+    fn test_manifest() {
+        let manifest: Manifest = toml::from_str(
+            "[second]\npart_one = 5\npart_two = \"ABC\"\n\n[third]\npart_two = 23\n",
+        )
+        .unwrap();
+        assert_eq!(manifest["second"].part_one, Some(Answer::Number(5)));
+        assert_eq!(
+            manifest["second"].part_two.as_ref().unwrap().to_string(),
+            "ABC"
+        );
+        assert_eq!(manifest["third"].part_one, None);
+        assert!(toml::from_str::<Manifest>("[second]\npart_three = 1\n").is_err());
+    }
+}
@@ -2,3 +2,73 @@
  * Use this file if you want to extract helpers from your solutions.
  * Example import from this file: `use aoc::helpers::example_fn;`.
  */
+
+/// A rectangular grid of bytes, parsed from lines of equal length. Used by the `grid` scaffold template.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid {
+    pub width: usize,
+    pub height: usize,
+    pub cells: Vec<u8>,
+}
+
+impl Grid {
+    pub fn parse(input: &str) -> Grid {
+        let lines: Vec<&[u8]> = input.lines().map(str::as_bytes).collect();
+        let width = lines.first().map_or(0, |line| line.len());
+        Grid {
+            width,
+            height: lines.len(),
+            cells: lines.concat(),
+        }
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Option<u8> {
+        (x < self.width && y < self.height).then(|| self.cells[y * self.width + x])
+    }
+
+    pub fn set(&mut self, x: usize, y: usize, value: u8) {
+        self.cells[y * self.width + x] = value;
+    }
+
+    /// Returns all positions, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+        (0..self.height).flat_map(move |y| (0..self.width).map(move |x| (x, y)))
+    }
+
+    /// Returns the first position holding `value`.
+    pub fn find(&self, value: u8) -> Option<(usize, usize)> {
+        self.positions()
+            .find(|&(x, y)| self.get(x, y) == Some(value))
+    }
+
+    /// Returns the positions above, left, right and below inside the grid.
+    pub fn neighbours(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        [(0, -1), (-1, 0), (1, 0), (0, 1)]
+            .into_iter()
+            .filter_map(move |(dx, dy)| {
+                let x = x.checked_add_signed(dx)?;
+                let y = y.checked_add_signed(dy)?;
+                self.get(x, y).map(|_| (x, y))
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_grid() {
+        let mut grid = Grid::parse("Sab\ncdE\n");
+        assert_eq!((grid.width, grid.height), (3, 2));
+        assert_eq!(grid.get(2, 1), Some(b'E'));
+        assert_eq!(grid.get(3, 0), None);
+        assert_eq!(grid.find(b'E'), Some((2, 1)));
+        assert_eq!(
+            grid.neighbours(0, 0).collect::<Vec<_>>(),
+            vec![(1, 0), (0, 1)]
+        );
+        grid.set(0, 0, b'a');
+        assert_eq!(grid.find(b'S'), None);
+    }
+}
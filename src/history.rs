@@ -0,0 +1,271 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::{bench::Stats, config, memory::MemoryStats};
+use serde::{Deserialize, Serialize};
+use std::{
+    fmt::Display,
+    fs::{self, create_dir_all, OpenOptions},
+    io::{self, Write},
+    path::PathBuf,
+    process::Command,
+};
+
+const HISTORY_FILE: &str = "history.jsonl";
+
+/// Returns the path of the benchmark history of the configured year, e.g. `src/benchmarks/history.jsonl`.
+pub fn path() -> PathBuf {
+    let config = config::get();
+    config.dir("benchmarks", config.year).join(HISTORY_FILE)
+}
+
+/// Benchmark statistics of one part. Part 0 is the parse stage.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PartTiming {
+    pub day: u8,
+    pub part: u8,
+    #[serde(flatten)]
+    pub stats: Stats,
+    /// heap usage, if the run was built with the `count-allocations` feature.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub memory: Option<MemoryStats>,
+}
+
+/// One benchmark run, stored as a line of the history file, see `path`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HistoryEntry {
+    /// the checked out git commit, or "unknown" outside of a git repository.
+    pub commit: String,
+    /// true if the working tree had uncommitted changes.
+    pub dirty: bool,
+    /// time of the run in RFC 3339 format.
+    pub date: String,
+    pub parts: Vec<PartTiming>,
+}
+
+/// A part whose median timing changed between a baseline run and the latest run.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Comparison {
+    pub day: u8,
+    pub part: u8,
+    /// commit of the run the baseline timing was taken from.
+    pub baseline_commit: String,
+    pub baseline_ns: u64,
+    pub latest_ns: u64,
+    /// peak heap bytes of both runs, if both counted allocations.
+    pub peak_bytes: Option<(u64, u64)>,
+}
+
+impl Comparison {
+    /// Returns how many times slower the latest timing is than the baseline.
+    pub fn ratio(&self) -> f64 {
+        self.latest_ns as f64 / self.baseline_ns.max(1) as f64
+    }
+
+    /// Returns how many times more heap memory the latest run used at its peak.
+    pub fn memory_ratio(&self) -> Option<f64> {
+        self.peak_bytes
+            .map(|(baseline, latest)| latest as f64 / baseline.max(1) as f64)
+    }
+
+    /// Returns true if the latest run is more than `threshold` percent slower than the baseline,
+    /// or used more than `threshold` percent more heap memory.
+    pub fn is_regression(&self, threshold: f64) -> bool {
+        let limit = 1.0 + threshold / 100.0;
+        self.ratio() > limit || self.memory_ratio().is_some_and(|ratio| ratio > limit)
+    }
+}
+
+#[derive(Debug)]
+pub enum HistoryError {
+    IoError(io::Error),
+    ParseError(serde_json::Error),
+    SerializeError(serde_json::Error),
+}
+
+impl Display for HistoryError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            HistoryError::IoError(e) => write!(f, "could not access benchmark history: {}", e),
+            HistoryError::ParseError(e) => write!(f, "could not parse benchmark history: {}", e),
+            HistoryError::SerializeError(e) => {
+                write!(f, "could not serialize benchmark history: {}", e)
+            }
+        }
+    }
+}
+
+impl HistoryEntry {
+    /// Creates an entry for the current commit and time.
+    pub fn new(parts: Vec<PartTiming>) -> HistoryEntry {
+        let commit = git(&["rev-parse", "HEAD"]).unwrap_or_else(|| "unknown".into());
+        let dirty = git(&["status", "--porcelain"]).is_some_and(|status| !status.is_empty());
+        HistoryEntry {
+            commit,
+            dirty,
+            date: chrono::Local::now().to_rfc3339_opts(chrono::SecondsFormat::Secs, false),
+            parts,
+        }
+    }
+
+    /// Returns the commit shortened for display, marked with `+` if the tree was dirty.
+    pub fn short_commit(&self) -> String {
+        let short: String = self.commit.chars().take(7).collect();
+        if self.dirty {
+            format!("{}+", short)
+        } else {
+            short
+        }
+    }
+
+    fn part(&self, day: u8, part: u8) -> Option<&PartTiming> {
+        self.parts.iter().find(|p| p.day == day && p.part == part)
+    }
+}
+
+/// Loads all entries of the benchmark history, oldest first. A missing file is treated as an empty history.
+pub fn load() -> Result<Vec<HistoryEntry>, HistoryError> {
+    match fs::read_to_string(path()) {
+        Ok(content) => parse(&content),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(vec![]),
+        Err(e) => Err(HistoryError::IoError(e)),
+    }
+}
+
+pub fn parse(content: &str) -> Result<Vec<HistoryEntry>, HistoryError> {
+    content
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| serde_json::from_str(line).map_err(HistoryError::ParseError))
+        .collect()
+}
+
+/// Appends an entry to the benchmark history.
+pub fn append(entry: &HistoryEntry) -> Result<(), HistoryError> {
+    let line = serde_json::to_string(entry).map_err(HistoryError::SerializeError)?;
+    let path = path();
+    if let Some(dir) = path.parent() {
+        create_dir_all(dir).map_err(HistoryError::IoError)?;
+    }
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .map_err(HistoryError::IoError)?;
+    writeln!(file, "{}", line).map_err(HistoryError::IoError)
+}
+
+/// Compares every part of the latest entry against the most recent earlier run of that part.
+/// If `baseline` is set, only runs of commits starting with it are considered as baseline.
+pub fn compare(entries: &[HistoryEntry], baseline: Option<&str>) -> Vec<Comparison> {
+    let Some((latest, earlier)) = entries.split_last() else {
+        return vec![];
+    };
+
+    latest
+        .parts
+        .iter()
+        .filter_map(|timing| {
+            let (entry, base) = earlier
+                .iter()
+                .rev()
+                .filter(|entry| baseline.is_none_or(|rev| entry.commit.starts_with(rev)))
+                .find_map(|entry| Some((entry, entry.part(timing.day, timing.part)?)))?;
+
+            Some(Comparison {
+                day: timing.day,
+                part: timing.part,
+                baseline_commit: entry.short_commit(),
+                baseline_ns: base.stats.median_ns,
+                latest_ns: timing.stats.median_ns,
+                peak_bytes: base
+                    .memory
+                    .zip(timing.memory)
+                    .map(|(base, latest)| (base.peak_bytes, latest.peak_bytes)),
+            })
+        })
+        .collect()
+}
+
+fn git(args: &[&str]) -> Option<String> {
+    let output = Command::new("git").args(args).output().ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    Some(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(commit: &str, timings: &[(u8, u8, u64)]) -> HistoryEntry {
+        HistoryEntry {
+            commit: commit.into(),
+            dirty: false,
+            date: "2022-12-24T12:00:00+01:00".into(),
+            parts: timings
+                .iter()
+                .map(|&(day, part, median_ns)| PartTiming {
+                    day,
+                    part,
+                    stats: Stats {
+                        samples: 10,
+                        min_ns: median_ns,
+                        median_ns,
+                        mean_ns: median_ns,
+                        stddev_ns: 0,
+                    },
+                    memory: None,
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn test_history_roundtrip() {
+        let entries = vec![entry("abc", &[(16, 1, 100)]), entry("def", &[(19, 2, 5)])];
+        let content: String = entries
+            .iter()
+            .map(|e| serde_json::to_string(e).unwrap() + "\n")
+            .collect();
+        assert_eq!(parse(&content).unwrap(), entries);
+    }
+
+    #[test]
+    fn test_compare() {
+        let entries = vec![
+            entry("aaa111", &[(16, 1, 100), (19, 1, 1000)]),
+            entry("bbb222", &[(16, 1, 110)]),
+            entry("ccc333", &[(16, 1, 330), (19, 1, 1100), (24, 1, 50)]),
+        ];
+
+        let comparisons = compare(&entries, None);
+        assert_eq!(comparisons.len(), 2);
+        assert_eq!(comparisons[0].baseline_commit, "bbb222");
+        assert!(comparisons[0].is_regression(20.0));
+        assert_eq!(comparisons[1].baseline_commit, "aaa111");
+        assert!(!comparisons[1].is_regression(20.0));
+
+        let comparisons = compare(&entries, Some("aaa"));
+        assert_eq!(comparisons[0].baseline_ns, 100);
+        assert!((comparisons[0].ratio() - 3.3).abs() < 1e-9);
+
+        let memory = |peak_bytes| {
+            Some(MemoryStats {
+                peak_bytes,
+                allocations: 1,
+            })
+        };
+        let mut entries = vec![
+            entry("aaa111", &[(24, 1, 100)]),
+            entry("bbb222", &[(24, 1, 100)]),
+        ];
+        entries[0].parts[0].memory = memory(1000);
+        entries[1].parts[0].memory = memory(3000);
+        let comparisons = compare(&entries, None);
+        assert_eq!(comparisons[0].peak_bytes, Some((1000, 3000)));
+        assert!(comparisons[0].is_regression(20.0));
+    }
+}
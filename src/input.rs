@@ -0,0 +1,193 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::{
+    config,
+    crypt::{self, CryptError},
+};
+use std::{
+    fmt::Display,
+    fs,
+    io::{self, Read},
+    path::{Path, PathBuf},
+};
+
+/// Passed as input path to read the input from stdin.
+pub const STDIN: &str = "-";
+/// Shown as path of the input in errors when reading from stdin.
+pub const STDIN_NAME: &str = "<stdin>";
+
+#[derive(Debug)]
+pub enum InputError {
+    Missing(PathBuf),
+    /// the file exists but contains only whitespace, e.g. the empty file left behind by scaffold.
+    Empty(PathBuf),
+    /// the file name says it belongs to another day, e.g. `src/inputs/07.txt` passed to day 8.
+    WrongDay {
+        path: PathBuf,
+        day: u8,
+        expected: u8,
+    },
+    IoError(PathBuf, io::Error),
+    /// the file is encrypted and could not be decrypted, see `crypt`.
+    Decrypt(PathBuf, CryptError),
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InputError::Missing(path) => write!(f, "input file {} does not exist", path.display()),
+            InputError::Empty(path) => write!(f, "input file {} is empty", path.display()),
+            InputError::WrongDay {
+                path,
+                day,
+                expected,
+            } => write!(
+                f,
+                "input file {} belongs to day {}, not day {}",
+                path.display(),
+                day,
+                expected
+            ),
+            InputError::Decrypt(path, e) => write!(f, "{}: {}", path.display(), e),
+            InputError::IoError(path, e) => {
+                write!(f, "could not read input file {}: {}", path.display(), e)
+            }
+        }
+    }
+}
+
+/// Reads the input of `day` from `path` and normalizes it.
+/// Files in the data folder have to belong to `day`, other paths are trusted.
+pub fn read(path: &Path, day: u8) -> Result<String, InputError> {
+    let checked = is_inside(path, &config::get().data_dir);
+    if let Some(other) = day_of_path(path).filter(|&other| checked && other != day) {
+        return Err(InputError::WrongDay {
+            path: path.to_path_buf(),
+            day: other,
+            expected: day,
+        });
+    }
+
+    let content = fs::read_to_string(path).map_err(|e| match e.kind() {
+        io::ErrorKind::NotFound => InputError::Missing(path.to_path_buf()),
+        _ => InputError::IoError(path.to_path_buf(), e),
+    })?;
+
+    prepare(path, content)
+}
+
+/// Reads the input from stdin and normalizes it.
+pub fn read_stdin() -> Result<String, InputError> {
+    let path = PathBuf::from(STDIN_NAME);
+    let mut content = String::new();
+    io::stdin()
+        .read_to_string(&mut content)
+        .map_err(|e| InputError::IoError(path.clone(), e))?;
+
+    prepare(&path, content)
+}
+
+/// Decrypts `content` if it is encrypted, then normalizes it.
+fn prepare(path: &Path, content: String) -> Result<String, InputError> {
+    let content = if crypt::is_encrypted(&content) {
+        crypt::load_key()
+            .and_then(|key| crypt::decrypt(&content, &key))
+            .map_err(|e| InputError::Decrypt(path.to_path_buf(), e))?
+    } else {
+        content
+    };
+
+    let content = normalize(&content);
+    if content.is_empty() {
+        return Err(InputError::Empty(path.to_path_buf()));
+    }
+    Ok(content)
+}
+
+/// Strips a byte order mark and trailing whitespace, and converts CRLF line endings to LF.
+pub fn normalize(content: &str) -> String {
+    let content = content.strip_prefix('\u{feff}').unwrap_or(content);
+    content.replace("\r\n", "\n").trim_end().to_string()
+}
+
+/// Returns true if `path` is inside `dir`, following symlinks of existing paths.
+fn is_inside(path: &Path, dir: &Path) -> bool {
+    let absolute = |path: &Path| {
+        fs::canonicalize(path)
+            .or_else(|_| std::path::absolute(path))
+            .unwrap_or_else(|_| path.to_path_buf())
+    };
+    absolute(path).starts_with(absolute(dir))
+}
+
+/// Returns the day a path belongs to, taken from a folder like `07/` or a file name like `07.txt`.
+/// The folder wins, so team inputs like `inputs/07/03.txt` belong to day 7.
+fn day_of_path(path: &Path) -> Option<u8> {
+    let stem = path.file_stem()?.to_str()?;
+    let folder = path.parent()?.file_name().and_then(|name| name.to_str());
+    [folder, Some(stem)]
+        .into_iter()
+        .flatten()
+        // named examples like `06-second.txt` belong to the day before the dash.
+        .find_map(|name| name.split('-').next()?.parse().ok())
+        .filter(|day| (1..=25).contains(day))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize() {
+        assert_eq!(
+            normalize("\u{feff}1000\r\n2000\r\n\r\n3000\r\n\r\n"),
+            "1000\n2000\n\n3000"
+        );
+        assert_eq!(normalize("A Y\nB X  \n\n\n"), "A Y\nB X");
+        assert_eq!(normalize("  \n\t\n"), "");
+    }
+
+    #[test]
+    fn test_day_of_path() {
+        assert_eq!(day_of_path(Path::new("src/inputs/07.txt")), Some(7));
+        assert_eq!(day_of_path(Path::new("src/inputs/22/alice.txt")), Some(22));
+        assert_eq!(day_of_path(Path::new("src/inputs/22/03.txt")), Some(22));
+        assert_eq!(
+            day_of_path(Path::new("src/examples/06-second.txt")),
+            Some(6)
+        );
+        assert_eq!(day_of_path(Path::new("/tmp/input.txt")), None);
+        assert_eq!(day_of_path(Path::new("2022.txt")), None);
+    }
+
+    #[test]
+    fn test_read_wrong_day() {
+        let result = read(Path::new("src/examples/07.txt"), 8);
+        assert!(matches!(
+            result,
+            Err(InputError::WrongDay {
+                day: 7,
+                expected: 8,
+                ..
+            })
+        ));
+        assert!(matches!(
+            read(Path::new("src/examples/99.txt"), 99),
+            Err(InputError::Missing(_))
+        ));
+        // paths outside the data folder are not checked.
+        assert!(matches!(
+            read(Path::new("/nonexistent/10.txt"), 6),
+            Err(InputError::Missing(_))
+        ));
+    }
+
+    #[test]
+    fn test_is_inside() {
+        let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("src");
+        assert!(is_inside(&dir.join("inputs/07.txt"), &dir));
+        assert!(!is_inside(Path::new("/tmp/10.txt"), &dir));
+    }
+}
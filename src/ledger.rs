@@ -0,0 +1,134 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::config;
+use serde::{Deserialize, Serialize};
+use std::{
+    fmt::Display,
+    fs::{self, create_dir_all},
+    io,
+    path::PathBuf,
+};
+
+/// Answers known for one part of a puzzle.
+#[derive(Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PartAnswers {
+    /// the answer accepted by advent of code.
+    pub accepted: Option<String>,
+    /// all answers that were submitted and rejected.
+    #[serde(default)]
+    pub rejected: Vec<String>,
+}
+
+/// The answer ledger of a day, stored in `answers/NN.toml` of the data folder.
+#[derive(Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Ledger {
+    #[serde(default)]
+    pub part_one: PartAnswers,
+    #[serde(default)]
+    pub part_two: PartAnswers,
+}
+
+#[derive(Debug)]
+pub enum LedgerError {
+    IoError(io::Error),
+    ParseError(toml::de::Error),
+    SerializeError(toml::ser::Error),
+}
+
+impl Display for LedgerError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LedgerError::IoError(e) => write!(f, "could not access answer ledger: {}", e),
+            LedgerError::ParseError(e) => write!(f, "could not parse answer ledger: {}", e),
+            LedgerError::SerializeError(e) => write!(f, "could not serialize answer ledger: {}", e),
+        }
+    }
+}
+
+impl Ledger {
+    /// Loads the ledger of the given day from the data folder of `year`, see `config::Config::dir`.
+    /// A missing ledger file is treated as an empty ledger.
+    pub fn load(day: u8, year: Option<u16>) -> Result<Ledger, LedgerError> {
+        match fs::read_to_string(get_ledger_path(day, year)) {
+            Ok(content) => Ledger::parse(&content),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Ledger::default()),
+            Err(e) => Err(LedgerError::IoError(e)),
+        }
+    }
+
+    pub fn parse(content: &str) -> Result<Ledger, LedgerError> {
+        toml::from_str(content).map_err(LedgerError::ParseError)
+    }
+
+    pub fn save(&self, day: u8, year: Option<u16>) -> Result<(), LedgerError> {
+        let content = toml::to_string(self).map_err(LedgerError::SerializeError)?;
+        let path = get_ledger_path(day, year);
+        if let Some(dir) = path.parent() {
+            create_dir_all(dir).map_err(LedgerError::IoError)?;
+        }
+        fs::write(path, content).map_err(LedgerError::IoError)
+    }
+
+    pub fn part(&self, part: u8) -> &PartAnswers {
+        match part {
+            1 => &self.part_one,
+            _ => &self.part_two,
+        }
+    }
+
+    pub fn part_mut(&mut self, part: u8) -> &mut PartAnswers {
+        match part {
+            1 => &mut self.part_one,
+            _ => &mut self.part_two,
+        }
+    }
+
+    /// Records `answer` as the accepted answer of the given part.
+    pub fn accept(&mut self, part: u8, answer: &str) {
+        self.part_mut(part).accepted = Some(answer.to_string());
+    }
+
+    /// Records `answer` as a rejected submission of the given part.
+    pub fn reject(&mut self, part: u8, answer: &str) {
+        let rejected = &mut self.part_mut(part).rejected;
+        if !rejected.iter().any(|r| r == answer) {
+            rejected.push(answer.to_string());
+        }
+    }
+}
+
+fn get_ledger_path(day: u8, year: Option<u16>) -> PathBuf {
+    config::get().year_path("answers", year, day, "toml")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ledger_roundtrip() {
+        let mut ledger = Ledger::default();
+        ledger.reject(1, "42");
+        ledger.reject(1, "42");
+        ledger.accept(1, "24000");
+        ledger.accept(2, "JCGZPBRH");
+
+        let content = toml::to_string(&ledger).unwrap();
+        let parsed = Ledger::parse(&content).unwrap();
+
+        assert_eq!(parsed, ledger);
+        assert_eq!(parsed.part(1).accepted.as_deref(), Some("24000"));
+        assert_eq!(parsed.part(1).rejected, vec!["42".to_string()]);
+        assert_eq!(parsed.part(2).accepted.as_deref(), Some("JCGZPBRH"));
+    }
+
+    #[test]
+    fn test_parse_partial_ledger() {
+        let ledger = Ledger::parse("[part_two]\nrejected = [\"1\", \"2\"]\n").unwrap();
+        assert_eq!(ledger.part(1), &PartAnswers::default());
+        assert_eq!(ledger.part(2).accepted, None);
+        assert_eq!(ledger.part(2).rejected.len(), 2);
+    }
+}
@@ -3,261 +3,499 @@
  * There is no need to edit this file unless you want to change template functionality.
  * Prefer `./helpers.rs` if you want to extract code from your solutions.
  */
-use std::env;
-use std::fs;
-use std::process::Command;
-use std::io;
-use std::io::Write;
-
+use bench::BenchOptions;
+use chrono::Local;
+use client::Client;
+use input::InputError;
+use ledger::Ledger;
+use std::fmt::Display;
+use std::path::{Path, PathBuf};
+use std::process;
+use std::time::Duration;
+use submission::{SubmissionState, Verdict};
+
+pub mod bench;
+pub mod client;
+pub mod compile;
+pub mod config;
+pub mod crypt;
+pub mod example;
 pub mod helpers;
+pub mod history;
+pub mod input;
+pub mod ledger;
+pub mod memory;
+pub mod puzzle;
+pub mod readme;
+pub mod runner;
+pub mod schedule;
+pub mod solution;
+#[cfg(feature = "solutions")]
+pub mod solutions;
+pub mod submission;
+pub mod team;
 
 pub const ANSI_ITALIC: &str = "\x1b[3m";
 pub const ANSI_BOLD: &str = "\x1b[1m";
 pub const ANSI_RESET: &str = "\x1b[0m";
 
+/// Runs a single part function on the input and prints its result.
+/// Solutions implementing `solution::Solution` are run with `solution::run` instead.
 #[macro_export]
 macro_rules! solve {
     ($day:expr, $part:expr, $solver:ident, $input:expr) => {{
-        use aoc::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET, submit_result};
-        use std::fmt::Display;
-        use std::time::Instant;
-
-        fn print_result<T: Display>(func: impl FnOnce(&str) -> Option<T>, input: &str) -> Option<T> {
-            let timer = Instant::now();
-            let result = func(input);
-            let elapsed = timer.elapsed();
-            match result {
-                Some(result) => {
-                    println!(
-                        "{} {}(elapsed: {:.2?}){}",
-                        result, ANSI_ITALIC, elapsed, ANSI_RESET
-                    );
-                    return Some(result);
-                }
-                None => {
-                    println!("not solved.");
-                    return None;
-                }
+        let ((result, elapsed), memory) =
+            $crate::memory::tracked(|| $crate::solution::timed(|| $solver($input)));
+        let timing = $crate::solution::describe_timing(elapsed, None, memory.as_ref());
+        $crate::solution::print_result($part, &result, &timing);
+        result
+    }};
+    ($day:expr, $part:expr, $solver:ident, $input:expr, $args:expr) => {{
+        let args: &$crate::SolveArgs = &$args;
+        if args.runs_part($part) {
+            let ((result, elapsed), memory) =
+                $crate::memory::tracked(|| $crate::solution::timed(|| $solver($input)));
+            let record = $crate::solution::report_part($day, $part, result, elapsed, memory, args);
+            if record.verified == Some(false) {
+                std::process::exit(1);
             }
         }
-
-        println!("🎄 {}Part {}{} 🎄", ANSI_BOLD, $part, ANSI_RESET);
-        let result = print_result($solver, $input);
-        match result {
-            Some(value) => submit_result($day, $part, value),
-            None => {},
-        }
     }};
 }
 
-pub fn submit_result(day: u8, part: i32, result: u32) {
-    println!("\nSubmitting day {}, part {}, result {}", day, part, result);
-
-    let cmd_args: Vec<String> = vec!["submit".to_string(), part.to_string(), result.to_string(), "-d".to_string(), day.to_string()];
-    match Command::new("aoc").args(cmd_args).output() {
-        Ok(cmd_output) => {
-            io::stdout()
-                .write_all(&cmd_output.stdout)
-                .expect("could not write cmd stdout to pipe.");
-            io::stderr()
-                .write_all(&cmd_output.stderr)
-                .expect("could not write cmd stderr to pipe.");
+/// Verifies or submits a result as requested by `args`.
+/// Returns whether the result matched the answer ledger, or None if it was not verified.
+pub fn handle_result(day: u8, part: u8, result: Option<&str>, args: &SolveArgs) -> Option<bool> {
+    if args.verify {
+        return Some(verify_result(
+            day,
+            part,
+            result,
+            args.record,
+            args.data_year(),
+        ));
+    }
+    if let Some(value) = result {
+        if args.submit {
+            submit_result(day, part, value, args.data_year());
         }
+    }
+    None
+}
+
+/// Submits `result` for the given day and part to Advent of Code.
+/// Any `Display` answer (integers of any width, strings) is submitted as its textual representation.
+/// Nothing is submitted while the cooldown of an earlier submission is running.
+/// Submissions are recorded in the submission state, accepted and rejected answers in the answer ledger of `year`.
+/// The answer is submitted to the event of `year`, or of `client::default_year` without a year.
+pub fn submit_result<T: Display>(
+    day: u8,
+    part: u8,
+    result: T,
+    year: Option<u16>,
+) -> Option<Verdict> {
+    let result = result.to_string();
+    let mut state = match SubmissionState::load() {
+        Ok(state) => state,
         Err(e) => {
-            eprintln!("failed to spawn aoc-cli: {}", e);
+            eprintln!("{}", e);
+            return None;
         }
+    };
+    if let Some(wait) = state.remaining_wait(Local::now()) {
+        eprintln!(
+            "Not submitting day {}, part {}: wait {} before submitting again.",
+            day,
+            part,
+            submission::format_wait(wait)
+        );
+        return None;
     }
 
-    println!("\n");
-}
+    println!("\nSubmitting day {}, part {}, result {}", day, part, result);
 
-pub fn read_file(folder: &str, day: u8) -> String {
-    let cwd = env::current_dir().unwrap();
+    let event = year.unwrap_or_else(client::default_year);
+    let response =
+        match Client::from_config().and_then(|client| client.submit(event, day, part, &result)) {
+            Ok(message) => submission::parse_response(&message),
+            Err(e) => {
+                eprintln!("failed to submit answer: {}", e);
+                return None;
+            }
+        };
+
+    println!("{}", response.message);
+    println!(
+        "{}Verdict:{} {}",
+        ANSI_BOLD,
+        ANSI_RESET,
+        response.verdict.label()
+    );
+    if let Some(wait) = response.wait {
+        println!(
+            "Next submission possible in {}.",
+            submission::format_wait(wait)
+        );
+    }
 
-    let filepath = cwd.join("src").join(folder).join(format!("{:02}.txt", day));
+    state.record(event, day, part, &result, &response, Local::now());
+    if let Err(e) = state.save() {
+        eprintln!("failed to record submission: {}", e);
+    }
+    record_submission(day, part, &result, response.verdict, year);
 
-    let f = fs::read_to_string(filepath);
-    f.expect("could not open input file")
+    println!("\n");
+    Some(response.verdict)
 }
 
-fn parse_time(val: &str, postfix: &str) -> f64 {
-    val.split(postfix).next().unwrap().parse().unwrap()
-}
+/// Stores the outcome of a submission in the answer ledger, if it was judged.
+fn record_submission(day: u8, part: u8, result: &str, verdict: Verdict, year: Option<u16>) {
+    if verdict != Verdict::Correct && !verdict.is_wrong() {
+        return;
+    }
 
-pub fn parse_exec_time(output: &str) -> f64 {
-    output.lines().fold(0_f64, |acc, l| {
-        if !l.contains("elapsed:") {
-            acc
+    let saved = Ledger::load(day, year).and_then(|mut ledger| {
+        if verdict == Verdict::Correct {
+            ledger.accept(part, result);
         } else {
-            let timing = l.split("(elapsed: ").last().unwrap();
-            // use `contains` istd. of `ends_with`: string may contain ANSI escape sequences.
-            // for possible time formats, see: https://github.com/rust-lang/rust/blob/1.64.0/library/core/src/time.rs#L1176-L1200
-            if timing.contains("ns)") {
-                acc // range below rounding precision.
-            } else if timing.contains("µs)") {
-                acc + parse_time(timing, "µs") / 1000_f64
-            } else if timing.contains("ms)") {
-                acc + parse_time(timing, "ms")
-            } else if timing.contains("s)") {
-                acc + parse_time(timing, "s") * 1000_f64
-            } else {
-                acc
-            }
+            ledger.reject(part, result);
         }
-    })
+        ledger.save(day, year)
+    });
+
+    if let Err(e) = saved {
+        eprintln!("failed to record submission: {}", e);
+    }
 }
 
-pub mod aoc_cli {
-    use std::{
-        fmt::Display,
-        fs::create_dir_all,
-        process::{Command, Output, Stdio},
+/// Compares `result` against the answer ledger. Returns false if it does not match the accepted answer.
+/// If `record` is set, a result for a part without an accepted answer is stored as accepted.
+/// The ledger is read from the data folder of `year`, see `config::Config::dir`.
+pub fn verify_result(
+    day: u8,
+    part: u8,
+    result: Option<&str>,
+    record: bool,
+    year: Option<u16>,
+) -> bool {
+    let mut ledger = match Ledger::load(day, year) {
+        Ok(ledger) => ledger,
+        Err(e) => {
+            eprintln!("{}", e);
+            return false;
+        }
     };
+    let answers = ledger.part(part);
 
-    pub enum AocCliError {
-        CommandNotFound,
-        CommandNotCallable,
-        BadExitStatus(Output),
-        IoError,
-    }
-
-    impl Display for AocCliError {
-        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-            match self {
-                AocCliError::CommandNotFound => write!(f, "aoc-cli is not present in environment."),
-                AocCliError::CommandNotCallable => write!(f, "aoc-cli could not be called."),
-                AocCliError::BadExitStatus(_) => write!(f, "aoc-cli exited with a non-zero status."),
-                AocCliError::IoError => write!(f, "could not write output files to file system."),
+    // verification messages are written to stderr to keep stdout parseable with `--json`.
+    match (answers.accepted.as_deref(), result) {
+        (Some(expected), Some(result)) if expected == result => {
+            eprintln!("{}✓ matches accepted answer.{}", ANSI_ITALIC, ANSI_RESET);
+            true
+        }
+        (Some(expected), _) => {
+            eprintln!(
+                "✗ day {}, part {}: expected {}, got {}.",
+                day,
+                part,
+                expected,
+                result.unwrap_or("nothing")
+            );
+            false
+        }
+        (None, Some(result)) if answers.rejected.iter().any(|r| r == result) => {
+            eprintln!(
+                "✗ day {}, part {}: {} was already rejected.",
+                day, part, result
+            );
+            false
+        }
+        (None, Some(result)) if record => {
+            ledger.accept(part, result);
+            if let Err(e) = ledger.save(day, year) {
+                eprintln!("{}", e);
+                return false;
             }
+            eprintln!("{}recorded as accepted answer.{}", ANSI_ITALIC, ANSI_RESET);
+            true
+        }
+        (None, _) => {
+            eprintln!("{}no accepted answer recorded.{}", ANSI_ITALIC, ANSI_RESET);
+            true
         }
     }
+}
 
-    pub fn check() -> Result<(), AocCliError> {
-        Command::new("aoc")
-            .arg("-V")
-            .output()
-            .map_err(|_| AocCliError::CommandNotFound)?;
-        Ok(())
+/// Returns the path of `<data_dir>/<folder>/NN.txt`, or `<data_dir>/<year>/<folder>/NN.txt` with a configured year, see `config::Config`.
+pub fn get_file_path(folder: &str, day: u8) -> PathBuf {
+    config::get().path(folder, day, "txt")
+}
+
+/// Reads and normalizes the file of a day in `folder`, see `get_file_path` and `input::read`.
+pub fn read_file(folder: &str, day: u8) -> Result<String, InputError> {
+    input::read(&get_file_path(folder, day), day)
+}
+
+/// Reads the example of a day of `year` for a test, see `config::Config::dir`. Panics with a hint while
+/// the example is missing or empty. Scaffolded tests are ignored until `cargo example` fills it in.
+pub fn read_example(year: Option<u16>, day: u8) -> String {
+    let path = config::get()
+        .dir("examples", year)
+        .join(format!("{:02}.txt", day));
+    match input::read(&path, day) {
+        Ok(input) => input,
+        Err(e @ (InputError::Missing(_) | InputError::Empty(_))) => {
+            panic!("{}, paste the example or run `cargo example {}`.", e, day)
+        }
+        Err(e) => panic!("{}", e),
     }
+}
 
-    pub fn read(day: u8, year: Option<u16>) -> Result<Output, AocCliError> {
-        // TODO: output local puzzle if present.
-        let args = build_args("read", &[], day, year);
-        call_aoc_cli(&args)
+/// Prints why an input could not be read. An empty input, as left behind by scaffold, is only a warning
+/// unless `fatal` is set, i.e. the caller exits because of it.
+pub fn report_input_error(error: &InputError, day: u8, fatal: bool) {
+    let level = if fatal { "error" } else { "warning" };
+    match error {
+        InputError::Empty(path) if path != Path::new(input::STDIN_NAME) => eprintln!(
+            "{}: {}, paste your input or run `cargo download {}`.",
+            level, error, day
+        ),
+        InputError::Empty(_) => eprintln!("{}: {}.", level, error),
+        _ => eprintln!("{}", error),
     }
+}
 
-    pub fn download(day: u8, year: Option<u16>) -> Result<Output, AocCliError> {
-        let input_path = get_input_path(day);
-
-        let puzzle_path = get_puzzle_path(day);
-        create_dir_all("src/puzzles").map_err(|_| AocCliError::IoError)?;
-
-        let args = build_args(
-            "download",
-            &[
-                "--overwrite".into(),
-                "--input-file".into(),
-                input_path.to_string(),
-                "--puzzle-file".into(),
-                puzzle_path.to_string(),
-            ],
-            day,
-            year,
-        );
+/// Command line arguments accepted by every day binary.
+/// example: `cargo solve 07 -- --part 1 --example`
+#[derive(Default)]
+pub struct SolveArgs {
+    /// only run this part (1 or 2), both parts are run if unset.
+    pub part: Option<u8>,
+    /// read the input from this path instead of the day's file in `inputs/`, `-` reads from stdin.
+    pub input: Option<String>,
+    /// read the input from the day's file in `examples/`.
+    pub example: bool,
+    /// submit each answer to Advent of Code, see `client::Client`.
+    pub submit: bool,
+    /// compare each answer against the answer ledger instead of submitting it.
+    pub verify: bool,
+    /// in verify mode, record answers of parts that have no accepted answer yet.
+    pub record: bool,
+    /// print one JSON record per part instead of the human readable output.
+    pub json: bool,
+    /// run each part repeatedly and report timing statistics.
+    pub bench: Option<BenchOptions>,
+    /// the year of the data folder, set by binaries scaffolded with a year. Without it, the configured year is used.
+    pub year: Option<u16>,
+}
 
-        let output = call_aoc_cli(&args)?;
+impl SolveArgs {
+    /// Parses the arguments of the running day binary. Exits the process on invalid arguments.
+    pub fn from_env() -> SolveArgs {
+        match SolveArgs::parse(pico_args::Arguments::from_env()) {
+            Ok(args) => args,
+            Err(e) => {
+                eprintln!("Failed to process arguments: {}", e);
+                process::exit(1);
+            }
+        }
+    }
 
-        if output.status.success() {
-            println!("---");
-            println!("🎄 Successfully wrote input to \"{}\".", &input_path);
-            println!("🎄 Successfully wrote puzzle to \"{}\".", &puzzle_path);
-            Ok(output)
-        } else {
-            Err(AocCliError::BadExitStatus(output))
+    fn parse(mut args: pico_args::Arguments) -> Result<SolveArgs, pico_args::Error> {
+        let parsed = SolveArgs {
+            part: args.opt_value_from_str(["-p", "--part"])?,
+            input: args.opt_value_from_str(["-i", "--input"])?,
+            example: args.contains(["-e", "--example"]),
+            submit: args.contains(["-s", "--submit"]),
+            verify: args.contains("--verify"),
+            record: args.contains("--record"),
+            json: args.contains("--json"),
+            bench: parse_bench_options(&mut args)?,
+            year: None,
+        };
+
+        let fail = |cause: &str| pico_args::Error::ArgumentParsingFailed {
+            cause: cause.into(),
+        };
+
+        // the input can also be passed as free argument, e.g. `cargo solve 06 -- -`.
+        let mut parsed = parsed;
+        if let Some(path) = args.opt_free_from_str::<String>()? {
+            if path.starts_with('-') && path != input::STDIN {
+                return Err(fail(&format!("unexpected argument {:?}", path)));
+            }
+            if parsed.input.is_some() {
+                return Err(fail("the input path was passed twice"));
+            }
+            parsed.input = Some(path);
         }
 
+        if let Some(unknown) = args.finish().first() {
+            return Err(fail(&format!("unexpected argument {:?}", unknown)));
+        }
+        if !matches!(parsed.part, None | Some(1) | Some(2)) {
+            return Err(fail("--part has to be 1 or 2"));
+        }
+        if parsed.example && parsed.input.is_some() {
+            return Err(fail("--example and --input cannot be combined"));
+        }
+        if (parsed.submit || parsed.verify) && parsed.example {
+            return Err(fail("example answers cannot be submitted or verified"));
+        }
+        if parsed.submit && parsed.verify {
+            return Err(fail("--submit and --verify cannot be combined"));
+        }
+        if parsed.submit && parsed.json {
+            return Err(fail("--submit and --json cannot be combined"));
+        }
+        if parsed.record && !parsed.verify {
+            return Err(fail("--record requires --verify"));
+        }
+        if parsed.submit && parsed.bench.is_some() {
+            return Err(fail("--submit and --bench cannot be combined"));
+        }
+
+        Ok(parsed)
     }
 
-    fn get_input_path(day: u8) -> String {
-        let day_padded = format!("{:02}", day);
-        format!("src/inputs/{}.txt", day_padded)
+    /// Returns true if the given part should be run.
+    pub fn runs_part(&self, part: u8) -> bool {
+        self.part.is_none_or(|p| p == part)
     }
 
-    fn get_puzzle_path(day: u8) -> String {
-        let day_padded = format!("{:02}", day);
-        format!("src/puzzles/{}.md", day_padded)
+    /// Returns the year of the data folder: the year of the binary, or else the configured year.
+    pub fn data_year(&self) -> Option<u16> {
+        self.year.or(config::get().year)
     }
 
-    fn build_args(command: &str, args: &[String], day: u8, year: Option<u16>) -> Vec<String> {
-        let mut cmd_args = args.to_vec();
+    /// Returns the path of the day's file in `folder` of the data year, e.g. `src/2021/inputs/08.txt`.
+    pub fn file_path(&self, folder: &str, day: u8) -> PathBuf {
+        config::get().year_path(folder, self.data_year(), day, "txt")
+    }
 
-        if let Some(year) = year {
-            cmd_args.push("--year".into());
-            cmd_args.push(year.to_string());
+    /// Reads the input selected by the arguments for the given day.
+    pub fn try_read_input(&self, day: u8) -> Result<String, InputError> {
+        match &self.input {
+            Some(path) if path == input::STDIN => input::read_stdin(),
+            Some(path) => input::read(Path::new(path), day),
+            None if self.example => input::read(&self.file_path("examples", day), day),
+            None => input::read(&self.file_path("inputs", day), day),
         }
+    }
 
-        cmd_args.append(&mut vec!["--day".into(), day.to_string(), command.into()]);
-
-        cmd_args
+    /// Reads the input selected by the arguments, exits if it cannot be read.
+    pub fn read_input(&self, day: u8) -> String {
+        self.try_read_input(day).unwrap_or_else(|e| {
+            report_input_error(&e, day, true);
+            process::exit(1);
+        })
     }
+}
 
-    fn call_aoc_cli(args: &[String]) -> Result<Output, AocCliError> {
-        if cfg!(debug_assertions) {
-            println!("Calling >aoc with: {}", args.join(" "));
-        }
+/// Parses `--bench` and its options `--warmup <runs>`, `--iterations <runs>` and `--budget <ms>`.
+/// The options are only accepted together with `--bench`.
+pub fn parse_bench_options(
+    args: &mut pico_args::Arguments,
+) -> Result<Option<BenchOptions>, pico_args::Error> {
+    let bench = args.contains("--bench");
+    let warmup: Option<u32> = args.opt_value_from_str("--warmup")?;
+    let iterations: Option<u32> = args.opt_value_from_str("--iterations")?;
+    let budget: Option<u64> = args.opt_value_from_str("--budget")?;
+
+    let fail = |cause: &str| pico_args::Error::ArgumentParsingFailed {
+        cause: cause.into(),
+    };
 
-        Command::new("aoc")
-            .args(args)
-            .stdout(Stdio::inherit())
-            .stderr(Stdio::inherit())
-            .output()
-            .map_err(|_| AocCliError::CommandNotCallable)
+    if !bench {
+        if warmup.is_some() || iterations.is_some() || budget.is_some() {
+            return Err(fail("--warmup, --iterations and --budget require --bench"));
+        }
+        return Ok(None);
+    }
+    if iterations == Some(0) {
+        return Err(fail("--iterations has to be at least 1"));
     }
-}
 
-/// copied from: https://github.com/rust-lang/rust/blob/1.64.0/library/std/src/macros.rs#L328-L333
-#[cfg(test)]
-macro_rules! assert_approx_eq {
-    ($a:expr, $b:expr) => {{
-        let (a, b) = (&$a, &$b);
-        assert!(
-            (*a - *b).abs() < 1.0e-6,
-            "{} is not approximately equal to {}",
-            *a,
-            *b
-        );
-    }};
+    let defaults = BenchOptions::default();
+    Ok(Some(BenchOptions {
+        warmup: warmup.unwrap_or(defaults.warmup),
+        iterations: iterations.unwrap_or(defaults.iterations),
+        budget: budget.map_or(defaults.budget, Duration::from_millis),
+    }))
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    #[test]
-    fn test_parse_exec_time() {
-        assert_approx_eq!(
-            parse_exec_time(&format!(
-                "🎄 Part 1 🎄\n0 (elapsed: 74.13ns){}\n🎄 Part 2 🎄\n0 (elapsed: 50.00ns){}",
-                ANSI_RESET, ANSI_RESET
-            )),
-            0_f64
-        );
-
-        assert_approx_eq!(
-            parse_exec_time("🎄 Part 1 🎄\n0 (elapsed: 755µs)\n🎄 Part 2 🎄\n0 (elapsed: 700µs)"),
-            1.455_f64
-        );
+    fn parse_args(args: &[&str]) -> Result<SolveArgs, pico_args::Error> {
+        SolveArgs::parse(pico_args::Arguments::from_vec(
+            args.iter().map(|arg| arg.into()).collect(),
+        ))
+    }
 
-        assert_approx_eq!(
-            parse_exec_time("🎄 Part 1 🎄\n0 (elapsed: 70µs)\n🎄 Part 2 🎄\n0 (elapsed: 1.45ms)"),
-            1.52_f64
-        );
+    #[test]
+    fn test_parse_solve_args() {
+        let args = parse_args(&[]).unwrap();
+        assert!(args.runs_part(1) && args.runs_part(2));
+        assert!(!args.submit && !args.example && args.input.is_none());
+
+        let args = parse_args(&["--part", "2", "--submit"]).unwrap();
+        assert!(!args.runs_part(1) && args.runs_part(2));
+        assert!(args.submit);
+
+        let args = parse_args(&["-e", "-p", "1"]).unwrap();
+        assert!(args.example && args.runs_part(1) && !args.runs_part(2));
+
+        let args = parse_args(&["--input", "big.txt"]).unwrap();
+        assert_eq!(args.input.as_deref(), Some("big.txt"));
+        let args = parse_args(&["--part", "1", "-"]).unwrap();
+        assert_eq!(args.input.as_deref(), Some("-"));
+        let args = parse_args(&["/tmp/big.txt", "--json"]).unwrap();
+        assert_eq!(args.input.as_deref(), Some("/tmp/big.txt"));
+        assert!(parse_args(&["--input", "big.txt", "other.txt"]).is_err());
+        assert!(parse_args(&["big.txt", "other.txt"]).is_err());
+
+        assert!(parse_args(&["--part", "3"]).is_err());
+        assert!(parse_args(&["--example", "--submit"]).is_err());
+        assert!(parse_args(&["--example", "--input", "big.txt"]).is_err());
+        assert!(parse_args(&["--sumbit"]).is_err());
+
+        let args = parse_args(&["--verify", "--record"]).unwrap();
+        assert!(args.verify && args.record && !args.submit);
+        assert!(parse_args(&["--record"]).is_err());
+        assert!(parse_args(&["--verify", "--submit"]).is_err());
+
+        let args = parse_args(&["--json", "--verify"]).unwrap();
+        assert!(args.json && args.verify);
+        assert!(parse_args(&["--json", "--submit"]).is_err());
+
+        let args = parse_args(&["--bench", "--iterations", "10", "--budget", "500"]).unwrap();
+        let options = args.bench.unwrap();
+        assert_eq!(options.iterations, 10);
+        assert_eq!(options.budget, Duration::from_millis(500));
+        assert_eq!(options.warmup, bench::DEFAULT_WARMUP);
+        assert!(parse_args(&[]).unwrap().bench.is_none());
+        assert!(parse_args(&["--iterations", "10"]).is_err());
+        assert!(parse_args(&["--bench", "--iterations", "0"]).is_err());
+        assert!(parse_args(&["--bench", "--submit"]).is_err());
+    }
 
-        assert_approx_eq!(
-            parse_exec_time(
-                "🎄 Part 1 🎄\n0 (elapsed: 10.3s)\n🎄 Part 2 🎄\n0 (elapsed: 100.50ms)"
-            ),
-            10400.50_f64
+    #[test]
+    fn test_file_path_of_year() {
+        // binaries scaffolded with `--year 2021` set the year, whatever year is configured.
+        let args = SolveArgs {
+            year: Some(2021),
+            ..SolveArgs::default()
+        };
+        assert_eq!(
+            args.file_path("inputs", 8),
+            config::get()
+                .data_dir
+                .join("2021")
+                .join("inputs")
+                .join("08.txt")
         );
+        assert_eq!(args.data_year(), Some(2021));
     }
 }
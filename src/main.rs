@@ -2,45 +2,396 @@
  * This file contains template code.
  * There is no need to edit this file unless you want to change template functionality.
  */
-use aoc::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
-use std::process::Command;
+use aoc::bench::BenchOptions;
+use aoc::history::{self, HistoryEntry, PartTiming};
+use aoc::runner::{self, Outcome, Status, StatusRecord, Workers};
+use aoc::solution;
+use aoc::team::{self, Cell};
+use aoc::{SolveArgs, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+use std::{
+    env, process,
+    time::{Duration, Instant},
+};
 
-fn main() {
-    let total: f64 = (1..=25)
-        .map(|day| {
-            let day = format!("{:02}", day);
+struct Args {
+    day: Option<u8>,
+    verify: bool,
+    record: bool,
+    json: bool,
+    bench: Option<BenchOptions>,
+    timeout: Duration,
+    jobs: usize,
+    /// run this day against every input in `src/inputs/NN/`.
+    team: Option<u8>,
+    /// internal: read the input of the worker from this path.
+    input: Option<String>,
+    /// internal: run only this day in-process and print JSON records for the runner.
+    worker: Option<u8>,
+}
 
-            let cmd = Command::new("cargo")
-                .args(["run", "--release", "--bin", &day])
-                .output()
-                .unwrap();
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    let parsed = Args {
+        verify: args.contains("--verify"),
+        record: args.contains("--record"),
+        json: args.contains("--json"),
+        bench: aoc::parse_bench_options(&mut args)?,
+        timeout: args
+            .opt_value_from_str("--timeout")?
+            .map_or(runner::DEFAULT_TIMEOUT, Duration::from_secs),
+        jobs: args.opt_value_from_str(["-j", "--jobs"])?.unwrap_or(1),
+        team: args.opt_value_from_str("--team")?,
+        input: args.opt_value_from_str("--input")?,
+        worker: args.opt_value_from_str("--worker")?,
+        day: args.opt_free_from_str()?,
+    };
+    if let Some(unknown) = args.finish().first() {
+        return Err(pico_args::Error::ArgumentParsingFailed {
+            cause: format!("unexpected argument {:?}", unknown),
+        });
+    }
+    Ok(parsed)
+}
 
-            println!("----------");
-            println!("{}| Day {} |{}", ANSI_BOLD, day, ANSI_RESET);
-            println!("----------");
+/// Runs a single day in this process. The runner reads the JSON records from stdout.
+#[cfg(feature = "solutions")]
+fn run_worker(day: u8, solve_args: SolveArgs) -> ! {
+    let Some(day) = aoc::solutions::registry()
+        .into_iter()
+        .find(|registered| registered.metadata.day == day)
+    else {
+        eprintln!("day {} is not registered.", day);
+        process::exit(1);
+    };
+    let input = match solve_args.try_read_input(day.metadata.day) {
+        Ok(input) => input,
+        Err(e) => {
+            let empty = matches!(e, aoc::input::InputError::Empty(_));
+            aoc::report_input_error(&e, day.metadata.day, !empty);
+            // an empty input is reported as unsolved, any other error as crashed.
+            process::exit(if empty { 0 } else { 1 });
+        }
+    };
+    (day.run)(
+        &input,
+        &SolveArgs {
+            json: true,
+            ..solve_args
+        },
+    );
+    process::exit(0);
+}
 
-            let output = String::from_utf8(cmd.stdout).unwrap();
-            let is_empty = output.is_empty();
+/// A runner built without the `solutions` feature runs its days in the workers it builds.
+#[cfg(not(feature = "solutions"))]
+fn run_worker(day: u8, _: SolveArgs) -> ! {
+    eprintln!(
+        "day {} cannot run in a runner built without solutions.",
+        day
+    );
+    process::exit(1);
+}
+
+/// Prints the records and status of a day in the format selected by `json`.
+fn print_outcome(outcome: &Outcome, timeout: Duration, json: bool) {
+    if !json {
+        println!("----------");
+        println!("{}| Day {:02} |{}", ANSI_BOLD, outcome.day, ANSI_RESET);
+        println!("----------");
+    }
+
+    if json {
+        if let Some(parse) = &outcome.parse {
+            println!("{}", serde_json::to_string(parse).unwrap());
+        }
+        outcome
+            .parts
+            .iter()
+            .for_each(|part| println!("{}", serde_json::to_string(part).unwrap()));
+        let status = StatusRecord {
+            day: outcome.day,
+            status: outcome.status,
+            wall_ns: outcome.wall_time.as_nanos() as u64,
+        };
+        println!("{}", serde_json::to_string(&status).unwrap());
+    } else {
+        if let Some(parse) = &outcome.parse {
+            solution::print_parse(parse);
+        }
+        outcome.parts.iter().for_each(solution::print_part);
+    }
 
+    // solutions and verification write to stderr, pass it on in both modes.
+    eprint!("{}", outcome.stderr);
+
+    if !json {
+        match outcome.status {
+            Status::Solved => {}
+            Status::Unsolved => println!("Not solved."),
+            Status::Crashed => match outcome.exit_status {
+                Some(status) => println!("Crashed ({}).", status),
+                None => println!("Crashed."),
+            },
+            Status::TimedOut => println!("Timed out after {:.0?}.", timeout),
+            Status::BuildFailed => println!("Build failed."),
+        }
+        // days without an input never start a worker.
+        if !outcome.wall_time.is_zero() {
             println!(
-                "{}",
-                if is_empty {
-                    "Not solved."
-                } else {
-                    output.trim()
-                }
+                "{}(wall-clock: {:.2?}){}",
+                ANSI_ITALIC, outcome.wall_time, ANSI_RESET
             );
+        }
+    }
+}
 
-            if is_empty {
-                0_f64
-            } else {
-                aoc::parse_exec_time(&output)
-            }
+/// Prints the days of each status other than solved, e.g. "crashed: 16, 19".
+fn print_summary(outcomes: &[Outcome]) {
+    let mut lines = vec![];
+    for status in [
+        Status::Unsolved,
+        Status::Crashed,
+        Status::TimedOut,
+        Status::BuildFailed,
+    ] {
+        let days: Vec<String> = outcomes
+            .iter()
+            .filter(|outcome| outcome.status == status)
+            .map(|outcome| format!("{:02}", outcome.day))
+            .collect();
+        if !days.is_empty() {
+            lines.push(format!("{}: {}", status.label(), days.join(", ")));
+        }
+    }
+
+    let solved = outcomes
+        .iter()
+        .filter(|outcome| outcome.status == Status::Solved)
+        .count();
+    println!(
+        "{}Summary:{} {} of {} solved",
+        ANSI_BOLD,
+        ANSI_RESET,
+        solved,
+        outcomes.len()
+    );
+    lines.iter().for_each(|line| println!("  {}", line));
+}
+
+/// Runs `day` against every team input and prints a pass/fail matrix.
+fn run_team(
+    workers: &Workers,
+    day: u8,
+    worker_args: &[String],
+    timeout: Duration,
+    jobs: usize,
+) -> ! {
+    if !workers.days.contains(&day) {
+        eprintln!("day {} is not registered.", day);
+        process::exit(1);
+    }
+    if let Some(errors) = workers.build_failed.get(&day) {
+        eprint!("{}", errors);
+        eprintln!("day {} does not compile.", day);
+        process::exit(1);
+    }
+    let inputs = match team::discover(day) {
+        Ok(inputs) if !inputs.is_empty() => inputs,
+        Ok(_) => {
+            eprintln!("no inputs in {}", team::get_team_dir(day).display());
+            process::exit(1);
+        }
+        Err(e) => {
+            eprintln!("could not read team inputs: {}", e);
+            process::exit(1);
+        }
+    };
+
+    let outcomes = team::run_inputs(&workers.exe, day, &inputs, worker_args, timeout, jobs);
+
+    let rows: Vec<(String, [Cell; 2])> = inputs
+        .iter()
+        .zip(&outcomes)
+        .map(|(input, outcome)| {
+            let expected = input.expected.as_ref();
+            let cells = [1, 2].map(|part| team::evaluate(outcome, expected, part));
+            (input.name.clone(), cells)
         })
-        .sum();
+        .collect();
 
     println!(
-        "{}Total:{} {}{:.2}ms{}",
-        ANSI_BOLD, ANSI_RESET, ANSI_ITALIC, total, ANSI_RESET
+        "{}Day {:02} on {} input(s){}",
+        ANSI_BOLD,
+        day,
+        inputs.len(),
+        ANSI_RESET
+    );
+    print!("{}", team::render_matrix(&rows));
+
+    // show what went wrong for inputs that crashed or timed out.
+    for (input, outcome) in inputs.iter().zip(&outcomes) {
+        if outcome.status.is_failure() && !outcome.stderr.is_empty() {
+            eprintln!("{}{}:{}", ANSI_BOLD, input.name, ANSI_RESET);
+            eprint!("{}", outcome.stderr);
+        }
+    }
+
+    let failed = rows
+        .iter()
+        .any(|(_, cells)| cells.iter().any(Cell::is_failure));
+    process::exit(if failed { 1 } else { 0 });
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) if args.jobs == 0 => {
+            eprintln!("Failed to process arguments: --jobs has to be at least 1");
+            process::exit(1);
+        }
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {}", e);
+            process::exit(1);
+        }
+    };
+
+    let solve_args = SolveArgs {
+        verify: args.verify,
+        record: args.record,
+        json: args.json,
+        bench: args.bench.clone(),
+        input: args.input.clone(),
+        ..SolveArgs::default()
+    };
+
+    if let Some(worker) = args.worker {
+        run_worker(worker, solve_args);
+    }
+
+    let workers = match Workers::prepare() {
+        Ok(workers) => workers,
+        Err(e) => {
+            eprintln!("{}", e);
+            process::exit(1);
+        }
+    };
+
+    // workers get the same arguments as the runner.
+    let worker_args: Vec<String> = env::args().skip(1).collect();
+
+    if let Some(day) = args.team {
+        run_team(&workers, day, &worker_args, args.timeout, args.jobs);
+    }
+
+    let days: Vec<u8> = workers
+        .days
+        .iter()
+        .copied()
+        .filter(|&day| args.day.is_none_or(|d| d == day))
+        .collect();
+
+    let timer = Instant::now();
+    let cpu_before = runner::children_cpu_time();
+    let outcomes = runner::run_days(
+        &workers,
+        &days,
+        args.input.as_deref(),
+        &worker_args,
+        args.timeout,
+        args.jobs,
+        |outcome| print_outcome(outcome, args.timeout, args.json),
     );
+    let wall_time = timer.elapsed();
+    let cpu_time = runner::children_cpu_time()
+        .zip(cpu_before)
+        .map(|(after, before)| after.saturating_sub(before));
+
+    let total: Duration = outcomes.iter().map(|outcome| outcome.elapsed()).sum();
+    let workers = outcomes
+        .iter()
+        .filter(|outcome| !outcome.wall_time.is_zero())
+        .count();
+
+    if !args.json {
+        // in bench mode, the solution time is the sum of the median timings.
+        println!(
+            "{}Solution time{}:{} {}{:.2}ms{}",
+            ANSI_BOLD,
+            if args.bench.is_some() {
+                " (median)"
+            } else {
+                ""
+            },
+            ANSI_RESET,
+            ANSI_ITALIC,
+            total.as_secs_f64() * 1000_f64,
+            ANSI_RESET
+        );
+        println!(
+            "{}CPU time:{} {}{} summed over {} worker(s){}",
+            ANSI_BOLD,
+            ANSI_RESET,
+            ANSI_ITALIC,
+            cpu_time.map_or("unavailable".to_string(), |time| format!("{:.2?}", time)),
+            workers,
+            ANSI_RESET
+        );
+        println!(
+            "{}Wall-clock:{} {}{:.2?} with {} job(s){}",
+            ANSI_BOLD, ANSI_RESET, ANSI_ITALIC, wall_time, args.jobs, ANSI_RESET
+        );
+        print_summary(&outcomes);
+    }
+
+    if args.bench.is_some() {
+        // the parse stage is stored as part 0.
+        let timings: Vec<PartTiming> = outcomes
+            .iter()
+            .flat_map(|outcome| {
+                let parse = outcome.parse.iter().filter_map(|parse| {
+                    Some(PartTiming {
+                        day: parse.day,
+                        part: 0,
+                        stats: parse.bench.clone()?,
+                        memory: parse.memory,
+                    })
+                });
+                let parts = outcome.parts.iter().filter_map(|part| {
+                    Some(PartTiming {
+                        day: part.day,
+                        part: part.part,
+                        stats: part.bench.clone()?,
+                        memory: part.memory,
+                    })
+                });
+                parse.chain(parts)
+            })
+            .collect();
+
+        if !timings.is_empty() {
+            if let Err(e) = history::append(&HistoryEntry::new(timings)) {
+                eprintln!("{}", e);
+            }
+        }
+    }
+
+    let mut failed = outcomes.iter().any(|outcome| outcome.status.is_failure());
+
+    if args.verify {
+        let changed: Vec<String> = outcomes
+            .iter()
+            .filter(|outcome| !outcome.verified())
+            .map(|outcome| format!("{:02}", outcome.day))
+            .collect();
+        if changed.is_empty() {
+            eprintln!("{}All answers match the ledger.{}", ANSI_BOLD, ANSI_RESET);
+        } else {
+            eprintln!("Answers changed for day(s): {}", changed.join(", "));
+            failed = true;
+        }
+    }
+
+    if failed {
+        process::exit(1);
+    }
 }
@@ -0,0 +1,168 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+
+const ANSI_DIM: &str = "\x1b[2m";
+/// used for emphasized code, which is how the puzzle text highlights answers.
+const ANSI_HIGHLIGHT: &str = "\x1b[1;33m";
+
+/// Renders the puzzle markdown written by `client::download` for the terminal.
+pub fn render(markdown: &str) -> String {
+    let lines: Vec<&str> = markdown.lines().collect();
+    let mut output = String::new();
+    let mut in_code = false;
+    let mut i = 0;
+
+    while i < lines.len() {
+        let line = lines[i];
+        i += 1;
+
+        if line.trim_start().starts_with("```") {
+            in_code = !in_code;
+            continue;
+        }
+        if in_code {
+            output += &format!("    {}{}{}\n", ANSI_DIM, line, ANSI_RESET);
+            continue;
+        }
+
+        if lines.get(i).is_some_and(|next| is_underline(next)) && !line.trim().is_empty() {
+            output += &format!("{}{}{}\n", ANSI_BOLD, render_inline(line), ANSI_RESET);
+            i += 1;
+        } else if let Some(heading) = line.strip_prefix('#') {
+            let heading = heading.trim_start_matches('#').trim();
+            output += &format!("{}{}{}\n", ANSI_BOLD, render_inline(heading), ANSI_RESET);
+        } else if let Some(item) = line.strip_prefix("* ") {
+            output += &format!("  • {}\n", render_inline(item));
+        } else {
+            output += &render_inline(line);
+            output.push('\n');
+        }
+    }
+
+    output
+}
+
+/// A setext heading underline, like `-----` or `=====`.
+fn is_underline(line: &str) -> bool {
+    let line = line.trim();
+    line.len() >= 3 && (line.chars().all(|c| c == '-') || line.chars().all(|c| c == '='))
+}
+
+/// Renders escapes, `*emphasis*`, `` `code` `` and `[links](url)` of a line.
+fn render_inline(line: &str) -> String {
+    let chars: Vec<char> = line.chars().collect();
+    let mut output = String::new();
+    let mut i = 0;
+
+    while i < chars.len() {
+        match chars[i] {
+            '\\' if i + 1 < chars.len() && chars[i + 1].is_ascii_punctuation() => {
+                output.push(chars[i + 1]);
+                i += 2;
+            }
+            '`' => match find(&chars, i + 1, '`') {
+                Some(end) => {
+                    let code: String = chars[i + 1..end].iter().collect();
+                    output += &render_code(&code);
+                    i = end + 1;
+                }
+                None => {
+                    output.push('`');
+                    i += 1;
+                }
+            },
+            '*' => match find(&chars, i + 1, '*') {
+                Some(end) if end > i + 1 => {
+                    let text: String = chars[i + 1..end].iter().collect();
+                    output += &format!("{}{}{}", ANSI_BOLD, render_inline(&text), ANSI_RESET);
+                    i = end + 1;
+                }
+                _ => {
+                    output.push('*');
+                    i += 1;
+                }
+            },
+            '[' => match link(&chars, i) {
+                Some((text, end)) => {
+                    output += &render_inline(&text);
+                    i = end;
+                }
+                None => {
+                    output.push('[');
+                    i += 1;
+                }
+            },
+            c => {
+                output.push(c);
+                i += 1;
+            }
+        }
+    }
+
+    output
+}
+
+fn render_code(code: &str) -> String {
+    match code
+        .strip_prefix('*')
+        .and_then(|code| code.strip_suffix('*'))
+    {
+        Some(answer) if !answer.is_empty() => {
+            format!("{}{}{}", ANSI_HIGHLIGHT, answer, ANSI_RESET)
+        }
+        _ => format!("{}{}{}", ANSI_ITALIC, code, ANSI_RESET),
+    }
+}
+
+/// Returns the position of the next unescaped `c` from `start`.
+fn find(chars: &[char], start: usize, c: char) -> Option<usize> {
+    let mut i = start;
+    while i < chars.len() {
+        match chars[i] {
+            '\\' => i += 2,
+            found if found == c => return Some(i),
+            _ => i += 1,
+        }
+    }
+    None
+}
+
+/// Reads a `[text](url)` link at `start`. Returns the text and the position after the link.
+fn link(chars: &[char], start: usize) -> Option<(String, usize)> {
+    let close = find(chars, start + 1, ']')?;
+    if chars.get(close + 1) != Some(&'(') {
+        return None;
+    }
+    let end = find(chars, close + 2, ')')?;
+    Some((chars[start + 1..close].iter().collect(), end + 1))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render() {
+        let markdown = "\\--- Day 1: Calorie Counting ---\n----------\n\nSanta's *reindeer* need [food](/2022/day/1/food).\n\n```\n1000\n2*3\n\n```\n\n* Total `*24000*` in `2 * 3`.\n\nUse 2\\*3\\*4 and x\\_y [1].\n";
+        let expected = format!(
+            "{b}--- Day 1: Calorie Counting ---{r}\n\nSanta's {b}reindeer{r} need food.\n\n    {d}1000{r}\n    {d}2*3{r}\n    {d}{r}\n\n  • Total {h}24000{r} in {i}2 * 3{r}.\n\nUse 2*3*4 and x_y [1].\n",
+            b = ANSI_BOLD,
+            r = ANSI_RESET,
+            d = ANSI_DIM,
+            h = ANSI_HIGHLIGHT,
+            i = ANSI_ITALIC,
+        );
+        assert_eq!(render(markdown), expected);
+    }
+
+    #[test]
+    fn test_render_heading() {
+        assert_eq!(
+            render("## --- Part Two ---\n"),
+            format!("{}--- Part Two ---{}\n", ANSI_BOLD, ANSI_RESET)
+        );
+    }
+}
@@ -0,0 +1,236 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::{bench::Stats, config::solutions_dir, history::HistoryEntry, ledger::Ledger};
+use std::{collections::BTreeMap, time::Duration};
+
+pub const STARS_MARKER: &str = "<!--- advent_readme_stars table --->";
+pub const TIMINGS_MARKER: &str = "<!--- benchmarking table --->";
+
+/// Reads the stars of `year` from the results table in `readme`, as `[part one, part two]` per day.
+/// Rows linking to another year are ignored.
+pub fn existing_stars(readme: &str, year: u16) -> BTreeMap<u8, [bool; 2]> {
+    let link = format!("](https://adventofcode.com/{}/day/", year);
+    readme
+        .lines()
+        .filter_map(|line| {
+            let (_, rest) = line.split_once(&link)?;
+            let (day, rest) = rest.split_once(')')?;
+            let cells: Vec<&str> = rest.split('|').map(str::trim).collect();
+            let star = |index: usize| cells.get(index).is_some_and(|cell| cell.contains('⭐'));
+            Some((day.parse().ok()?, [star(1), star(2)]))
+        })
+        .collect()
+}
+
+/// Renders the results table in the format of the `advent-readme-stars` action.
+/// A part gets a star if it has an accepted answer or already had a star in `existing`, so days solved
+/// before the answer ledger existed keep their stars. Only days with at least one star are listed.
+pub fn stars_table(
+    year: u16,
+    ledgers: &[(u8, Ledger)],
+    existing: &BTreeMap<u8, [bool; 2]>,
+) -> String {
+    let mut table = format!(
+        "## {} Results\n\n| Day | Part 1 | Part 2 |\n| :---: | :---: | :---: |\n",
+        year
+    );
+
+    for (day, ledger) in ledgers {
+        let kept = existing.get(day).copied().unwrap_or_default();
+        let solved = |part: u8| ledger.part(part).accepted.is_some() || kept[part as usize - 1];
+        let star = |part: u8| if solved(part) { "⭐" } else { " " };
+        if !solved(1) && !solved(2) {
+            continue;
+        }
+        table += &format!(
+            "| [Day {}](https://adventofcode.com/{}/day/{}) | {} | {} |\n",
+            day,
+            year,
+            day,
+            star(1),
+            star(2)
+        );
+    }
+
+    table
+}
+
+/// Returns the most recent benchmark statistics of each part in the history.
+pub fn latest_timings(entries: &[HistoryEntry]) -> BTreeMap<(u8, u8), Stats> {
+    entries
+        .iter()
+        .flat_map(|entry| entry.parts.iter())
+        .map(|timing| ((timing.day, timing.part), timing.stats.clone()))
+        .collect()
+}
+
+/// Renders the median timing of the parse stage and every benchmarked part, and their sum.
+/// Each day links to its solution of `year`, see `config::solutions_dir`.
+pub fn timings_table(timings: &BTreeMap<(u8, u8), Stats>, year: Option<u16>) -> String {
+    let mut table = String::from(
+        "## Benchmarks\n\n| Day | Parse | Part 1 | Part 2 |\n| :---: | :---: | :---: | :---: |\n",
+    );
+
+    let mut days: Vec<u8> = timings.keys().map(|&(day, _)| day).collect();
+    days.dedup();
+
+    let timing = |day: u8, part: u8| match timings.get(&(day, part)) {
+        Some(stats) => format!("`{:.2?}`", stats.median()),
+        None => "-".into(),
+    };
+
+    for day in days {
+        table += &format!(
+            "| [Day {}](./{}/day{:02}.rs) | {} | {} | {} |\n",
+            day,
+            solutions_dir(year),
+            day,
+            timing(day, 0),
+            timing(day, 1),
+            timing(day, 2)
+        );
+    }
+
+    let total: Duration = timings.values().map(|stats| stats.median()).sum();
+    table += &format!(
+        "\n**Total: {:.2}ms** _(median of each part)_\n",
+        total.as_secs_f64() * 1000_f64
+    );
+    table
+}
+
+/// Replaces the content between the two occurrences of `marker` in `readme`.
+/// Returns None if `readme` does not contain the marker twice.
+pub fn replace_section(readme: &str, marker: &str, content: &str) -> Option<String> {
+    let start = readme.find(marker)? + marker.len();
+    let end = start + readme[start..].find(marker)?;
+    Some(format!(
+        "{}\n{}{}",
+        &readme[..start],
+        content,
+        &readme[end..]
+    ))
+}
+
+/// Inserts an empty section delimited by `marker` right after the section delimited by `after`.
+/// Returns None if `readme` does not contain `after` twice.
+pub fn insert_section(readme: &str, after: &str, marker: &str) -> Option<String> {
+    let start = readme.find(after)? + after.len();
+    let end = start + readme[start..].find(after)? + after.len();
+    Some(format!(
+        "{}\n\n{}\n{}{}",
+        &readme[..end],
+        marker,
+        marker,
+        &readme[end..]
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::history::PartTiming;
+
+    fn stats(median_ns: u64) -> Stats {
+        Stats {
+            samples: 1,
+            min_ns: median_ns,
+            median_ns,
+            mean_ns: median_ns,
+            stddev_ns: 0,
+        }
+    }
+
+    #[test]
+    fn test_stars_table() {
+        let mut solved = Ledger::default();
+        solved.accept(1, "24000");
+        solved.accept(2, "45000");
+        let mut half = Ledger::default();
+        half.accept(1, "7");
+
+        let ledgers = [(1, solved), (2, Ledger::default()), (3, half)];
+        let table = stars_table(2022, &ledgers, &BTreeMap::new());
+        assert_eq!(
+            table,
+            "## 2022 Results\n\n| Day | Part 1 | Part 2 |\n| :---: | :---: | :---: |\n\
+             | [Day 1](https://adventofcode.com/2022/day/1) | ⭐ | ⭐ |\n\
+             | [Day 3](https://adventofcode.com/2022/day/3) | ⭐ |   |\n"
+        );
+
+        // stars without a ledger entry are kept.
+        let existing = existing_stars(&table, 2022);
+        assert_eq!(existing[&3], [true, false]);
+        assert!(existing_stars(&table, 2021).is_empty());
+        let empty: Vec<(u8, Ledger)> = (1..=3).map(|day| (day, Ledger::default())).collect();
+        assert_eq!(stars_table(2022, &empty, &existing), table);
+    }
+
+    #[test]
+    fn test_timings_table() {
+        let entries = vec![
+            HistoryEntry {
+                commit: "a".into(),
+                dirty: false,
+                date: "".into(),
+                parts: vec![
+                    PartTiming {
+                        day: 1,
+                        part: 0,
+                        stats: stats(500_000),
+                        memory: None,
+                    },
+                    PartTiming {
+                        day: 1,
+                        part: 1,
+                        stats: stats(5_000_000),
+                        memory: None,
+                    },
+                    PartTiming {
+                        day: 1,
+                        part: 2,
+                        stats: stats(1_000_000),
+                        memory: None,
+                    },
+                ],
+            },
+            HistoryEntry {
+                commit: "b".into(),
+                dirty: false,
+                date: "".into(),
+                parts: vec![PartTiming {
+                    day: 1,
+                    part: 1,
+                    stats: stats(2_000_000),
+                    memory: None,
+                }],
+            },
+        ];
+
+        let table = timings_table(&latest_timings(&entries), None);
+        assert!(table.contains(
+            "| [Day 1](./src/solutions/day01.rs) | `500.00µs` | `2.00ms` | `1.00ms` |\n"
+        ));
+        assert!(table.ends_with("**Total: 3.50ms** _(median of each part)_\n"));
+        assert!(timings_table(&latest_timings(&entries), Some(2021))
+            .contains("| [Day 1](./src/solutions/y2021/day01.rs) |"));
+    }
+
+    #[test]
+    fn test_replace_section() {
+        let readme = "# AoC\n<!-- m -->\nold\n<!-- m -->\nrest\n";
+        assert_eq!(
+            replace_section(readme, "<!-- m -->", "new\n").unwrap(),
+            "# AoC\n<!-- m -->\nnew\n<!-- m -->\nrest\n"
+        );
+        assert!(replace_section("# AoC\n", "<!-- m -->", "new\n").is_none());
+
+        let readme = insert_section(readme, "<!-- m -->", "<!-- t -->").unwrap();
+        assert_eq!(
+            readme,
+            "# AoC\n<!-- m -->\nold\n<!-- m -->\n\n<!-- t -->\n<!-- t -->\nrest\n"
+        );
+    }
+}
@@ -0,0 +1,326 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::solution::{ParseRecord, PartRecord};
+use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::BTreeMap,
+    io::{self, Read},
+    path::{Path, PathBuf},
+    process::{Command, ExitStatus, Stdio},
+    sync::mpsc,
+    thread,
+    time::{Duration, Instant},
+};
+use wait_timeout::ChildExt;
+
+pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// How running a day ended.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Status {
+    Solved,
+    /// no input file, or a part returned None.
+    Unsolved,
+    /// the worker panicked or exited with an error.
+    Crashed,
+    /// the worker was killed after exceeding the timeout.
+    TimedOut,
+    /// the solution did not compile, so the workers were built without it.
+    BuildFailed,
+}
+
+impl Status {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Status::Solved => "solved",
+            Status::Unsolved => "unsolved",
+            Status::Crashed => "crashed",
+            Status::TimedOut => "timed out",
+            Status::BuildFailed => "build failed",
+        }
+    }
+
+    /// Returns true if the runner should exit with a non-zero status.
+    pub fn is_failure(&self) -> bool {
+        matches!(
+            self,
+            Status::Crashed | Status::TimedOut | Status::BuildFailed
+        )
+    }
+}
+
+/// Machine readable status of a day, printed as a JSON line after its parts with `--json`.
+#[derive(Debug, Serialize)]
+pub struct StatusRecord {
+    pub day: u8,
+    pub status: Status,
+    /// wall-clock time of the worker process.
+    pub wall_ns: u64,
+}
+
+/// A JSON line printed by a worker.
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum WorkerRecord {
+    Part(PartRecord),
+    Parse(ParseRecord),
+}
+
+/// Everything the runner learned from running one day in a worker process.
+pub struct Outcome {
+    pub day: u8,
+    pub status: Status,
+    pub parse: Option<ParseRecord>,
+    pub parts: Vec<PartRecord>,
+    /// everything the worker wrote to stderr, including panic messages.
+    pub stderr: String,
+    /// the exit status of a crashed worker.
+    pub exit_status: Option<ExitStatus>,
+    /// wall-clock time of the worker process.
+    pub wall_time: Duration,
+}
+
+impl Outcome {
+    /// Returns an outcome for a day that was not run.
+    pub fn unsolved(day: u8) -> Outcome {
+        Outcome {
+            day,
+            status: Status::Unsolved,
+            parse: None,
+            parts: vec![],
+            stderr: String::new(),
+            exit_status: None,
+            wall_time: Duration::ZERO,
+        }
+    }
+
+    /// Returns the combined execution time of parsing and all parts that finished.
+    pub fn elapsed(&self) -> Duration {
+        let parse = self.parse.as_ref().map_or(0, |parse| parse.parse_ns);
+        Duration::from_nanos(parse + self.parts.iter().map(|p| p.duration_ns).sum::<u64>())
+    }
+
+    /// Returns false if any answer did not match the answer ledger in verify mode.
+    pub fn verified(&self) -> bool {
+        self.parts.iter().all(|part| part.verified != Some(false))
+    }
+}
+
+/// The binary the workers run and the registered days.
+pub struct Workers {
+    pub exe: PathBuf,
+    /// all registered days, including the ones that do not compile.
+    pub days: Vec<u8>,
+    /// the compiler errors of the days that do not compile.
+    pub build_failed: BTreeMap<u8, String>,
+}
+
+impl Workers {
+    /// A runner built with the `solutions` feature is its own worker. Otherwise, the workers are built
+    /// first and the days that do not compile are left out, see `compile::build_workers`.
+    pub fn prepare() -> Result<Workers, String> {
+        #[cfg(feature = "solutions")]
+        {
+            Ok(Workers {
+                exe: std::env::current_exe().map_err(|e| e.to_string())?,
+                days: crate::solutions::registry()
+                    .iter()
+                    .map(|day| day.metadata.day)
+                    .collect(),
+                build_failed: BTreeMap::new(),
+            })
+        }
+        #[cfg(not(feature = "solutions"))]
+        {
+            let year = crate::compile::registry_year();
+            let days = crate::compile::registered_days(year)
+                .map_err(|e| format!("could not read the registered days: {}", e))?;
+            let build = crate::compile::build_workers()?;
+            Ok(Workers {
+                build_failed: build.failed_days(year),
+                exe: build.exe,
+                days,
+            })
+        }
+    }
+}
+
+/// Runs `days` on up to `jobs` worker processes at a time. `input` is the `--input` passed to the workers, if any.
+/// `on_outcome` is called with the outcome of each day in the order of `days`, as soon as all earlier days are done.
+pub fn run_days(
+    workers: &Workers,
+    days: &[u8],
+    input: Option<&str>,
+    worker_args: &[String],
+    timeout: Duration,
+    jobs: usize,
+    mut on_outcome: impl FnMut(&Outcome),
+) -> Vec<Outcome> {
+    let pool = rayon::ThreadPoolBuilder::new()
+        .num_threads(jobs)
+        .build()
+        .expect("could not start runner threads");
+    let (sender, receiver) = mpsc::channel();
+
+    thread::scope(|scope| {
+        scope.spawn(|| {
+            pool.install(|| {
+                days.par_iter()
+                    .enumerate()
+                    .for_each_with(sender, |sender, (index, &day)| {
+                        let _ = sender.send((
+                            index,
+                            run_or_skip(workers, day, input, worker_args, timeout),
+                        ));
+                    })
+            })
+        });
+
+        // outcomes arrive in any order, hold them back until all earlier days were reported.
+        let mut pending = BTreeMap::new();
+        let mut outcomes = vec![];
+        for (index, outcome) in receiver {
+            pending.insert(index, outcome);
+            while let Some(outcome) = pending.remove(&outcomes.len()) {
+                on_outcome(&outcome);
+                outcomes.push(outcome);
+            }
+        }
+        outcomes
+    })
+}
+
+/// Runs `day` if it compiled and the input its worker reads exists, otherwise reports it as build failed or unsolved.
+fn run_or_skip(
+    workers: &Workers,
+    day: u8,
+    input: Option<&str>,
+    worker_args: &[String],
+    timeout: Duration,
+) -> Outcome {
+    if let Some(errors) = workers.build_failed.get(&day) {
+        return Outcome {
+            status: Status::BuildFailed,
+            stderr: errors.clone(),
+            ..Outcome::unsolved(day)
+        };
+    }
+    let path = match input {
+        Some(path) => PathBuf::from(path),
+        None => crate::get_file_path("inputs", day),
+    };
+    if input != Some(crate::input::STDIN) && !path.exists() {
+        return Outcome::unsolved(day);
+    }
+
+    run_day(&workers.exe, day, worker_args, timeout).unwrap_or_else(|e| Outcome {
+        status: Status::Crashed,
+        stderr: format!("failed to start worker for day {}: {}\n", day, e),
+        ..Outcome::unsolved(day)
+    })
+}
+
+/// Runs `day` in a worker process, which is `exe` called with `--worker <day>` and `worker_args`.
+/// The worker is killed if it runs longer than `timeout`.
+pub fn run_day(
+    exe: &Path,
+    day: u8,
+    worker_args: &[String],
+    timeout: Duration,
+) -> io::Result<Outcome> {
+    let timer = Instant::now();
+    let mut child = Command::new(exe)
+        .args(worker_args)
+        .args(["--worker".to_string(), day.to_string()])
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()?;
+
+    // read both pipes while waiting, so that a chatty worker cannot block on a full pipe.
+    let stdout = read_in_background(child.stdout.take());
+    let stderr = read_in_background(child.stderr.take());
+
+    let exit_status = match child.wait_timeout(timeout)? {
+        Some(status) => Some(status),
+        None => {
+            child.kill()?;
+            child.wait()?;
+            None
+        }
+    };
+    let wall_time = timer.elapsed();
+
+    let stdout = stdout.join().unwrap_or_default();
+    let stderr = stderr.join().unwrap_or_default();
+
+    let mut outcome = Outcome {
+        stderr,
+        wall_time,
+        ..Outcome::unsolved(day)
+    };
+    let mut other_output = String::new();
+    for line in stdout.lines() {
+        match serde_json::from_str(line) {
+            Ok(WorkerRecord::Parse(record)) => outcome.parse = Some(record),
+            Ok(WorkerRecord::Part(record)) => outcome.parts.push(record),
+            // anything else a solution printed to stdout is passed on with stderr.
+            Err(_) => other_output += &format!("{}\n", line),
+        }
+    }
+    outcome.stderr = other_output + &outcome.stderr;
+
+    outcome.status = match exit_status {
+        None => Status::TimedOut,
+        Some(status) if !status.success() => {
+            outcome.exit_status = Some(status);
+            Status::Crashed
+        }
+        Some(_) if outcome.parts.is_empty() => Status::Unsolved,
+        Some(_) if outcome.parts.iter().any(|part| part.answer.is_none()) => Status::Unsolved,
+        Some(_) => Status::Solved,
+    };
+
+    Ok(outcome)
+}
+
+/// Returns the user and system CPU time of all child processes that were waited for so far.
+/// The difference before and after `run_days` is the CPU time its workers used. None if unsupported.
+pub fn children_cpu_time() -> Option<Duration> {
+    #[cfg(unix)]
+    {
+        let mut usage = std::mem::MaybeUninit::<libc::rusage>::zeroed();
+        // SAFETY: getrusage only writes to the passed struct, which is fully initialized on success.
+        let usage = unsafe {
+            if libc::getrusage(libc::RUSAGE_CHILDREN, usage.as_mut_ptr()) != 0 {
+                return None;
+            }
+            usage.assume_init()
+        };
+        let time = |time: libc::timeval| {
+            Duration::from_secs(time.tv_sec as u64) + Duration::from_micros(time.tv_usec as u64)
+        };
+        Some(time(usage.ru_utime) + time(usage.ru_stime))
+    }
+    #[cfg(not(unix))]
+    {
+        None
+    }
+}
+
+fn read_in_background(pipe: Option<impl Read + Send + 'static>) -> thread::JoinHandle<String> {
+    thread::spawn(move || {
+        let mut content = String::new();
+        if let Some(mut pipe) = pipe {
+            let mut bytes = vec![];
+            if pipe.read_to_end(&mut bytes).is_ok() {
+                content = String::from_utf8_lossy(&bytes).into_owned();
+            }
+        }
+        content
+    })
+}
@@ -0,0 +1,175 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use chrono::{DateTime, Datelike, FixedOffset, TimeZone, Utc};
+use std::{fmt::Display, io::Write, thread, time::Duration};
+
+/// Puzzles unlock at midnight US Eastern time. December is always outside daylight saving time, so this is UTC-5.
+const EVENT_OFFSET_SECONDS: i32 = -5 * 3600;
+/// Waiting stops a little after the unlock, so that the puzzle is served by the time it is requested.
+const UNLOCK_MARGIN: Duration = Duration::from_secs(2);
+
+fn event_timezone() -> FixedOffset {
+    FixedOffset::east_opt(EVENT_OFFSET_SECONDS).unwrap()
+}
+
+/// Returns when the puzzle of `day` unlocks.
+pub fn unlock_time(year: u16, day: u8) -> DateTime<Utc> {
+    event_timezone()
+        .with_ymd_and_hms(year as i32, 12, day as u32, 0, 0, 0)
+        .unwrap()
+        .with_timezone(&Utc)
+}
+
+/// Returns how long until the puzzle of `day` unlocks, or None if it is unlocked at `now`.
+pub fn time_until_unlock(year: u16, day: u8, now: DateTime<Utc>) -> Option<Duration> {
+    (unlock_time(year, day) - now)
+        .to_std()
+        .ok()
+        .filter(|wait| !wait.is_zero())
+}
+
+/// Returns the day whose puzzle unlocked last at `now`, if an event is running.
+pub fn today(now: DateTime<Utc>) -> Option<(u16, u8)> {
+    let now = now.with_timezone(&event_timezone());
+    if now.month() == 12 && now.day() <= 25 {
+        Some((now.year() as u16, now.day() as u8))
+    } else {
+        None
+    }
+}
+
+/// Returns the year of the latest event that has started at `now`.
+pub fn latest_year(now: DateTime<Utc>) -> u16 {
+    let now = now.with_timezone(&event_timezone());
+    match now.month() {
+        12 => now.year() as u16,
+        _ => now.year() as u16 - 1,
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum DayError {
+    /// the day is not between 1 and 25.
+    OutOfRange(u8),
+    /// no day was given, and today's puzzle does not belong to the year.
+    NoneToday(u16),
+}
+
+impl Display for DayError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DayError::OutOfRange(day) => {
+                write!(f, "Day {} is not part of the event, days are 1 to 25.", day)
+            }
+            DayError::NoneToday(year) => write!(
+                f,
+                "No puzzle of {} unlocked today, specify a day (1 to 25).",
+                year
+            ),
+        }
+    }
+}
+
+/// Returns `day` if it is part of the event. Without a day, returns the day unlocked last at `now`
+/// if an event of `year` is running, so that a command never defaults to today's day of another year.
+pub fn resolve_day(day: Option<u8>, year: u16, now: DateTime<Utc>) -> Result<u8, DayError> {
+    match day {
+        Some(day @ 1..=25) => Ok(day),
+        Some(day) => Err(DayError::OutOfRange(day)),
+        None => match today(now) {
+            Some((today_year, day)) if today_year == year => Ok(day),
+            _ => Err(DayError::NoneToday(year)),
+        },
+    }
+}
+
+/// Formats a countdown like "1d 02:03:04".
+pub fn format_countdown(wait: Duration) -> String {
+    let seconds = wait.as_secs() + u64::from(wait.subsec_nanos() > 0);
+    let (days, hours, minutes, seconds) = (
+        seconds / 86400,
+        seconds / 3600 % 24,
+        seconds / 60 % 60,
+        seconds % 60,
+    );
+    match days {
+        0 => format!("{:02}:{:02}:{:02}", hours, minutes, seconds),
+        _ => format!("{}d {:02}:{:02}:{:02}", days, hours, minutes, seconds),
+    }
+}
+
+/// Blocks until the puzzle of `day` unlocks, printing a countdown.
+pub fn wait_for_unlock(year: u16, day: u8) {
+    while let Some(wait) = time_until_unlock(year, day, Utc::now()) {
+        print!("\r🎄 Day {} unlocks in {} ", day, format_countdown(wait));
+        let _ = std::io::stdout().flush();
+        thread::sleep(wait.min(Duration::from_secs(1)));
+    }
+    println!("\r🎄 Day {} is unlocked!{}", day, " ".repeat(16));
+    thread::sleep(UNLOCK_MARGIN);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn utc(month: u32, day: u32, hour: u32, minute: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2022, month, day, hour, minute, 0)
+            .unwrap()
+    }
+
+    #[test]
+    fn test_unlock_time() {
+        assert_eq!(unlock_time(2022, 7), utc(12, 7, 5, 0));
+        assert_eq!(
+            time_until_unlock(2022, 7, utc(12, 7, 4, 30)),
+            Some(Duration::from_secs(1800))
+        );
+        assert_eq!(time_until_unlock(2022, 7, utc(12, 7, 5, 0)), None);
+        assert_eq!(time_until_unlock(2022, 1, utc(12, 20, 0, 0)), None);
+    }
+
+    #[test]
+    fn test_today() {
+        // still December 6 in New York.
+        assert_eq!(today(utc(12, 7, 4, 59)), Some((2022, 6)));
+        assert_eq!(today(utc(12, 7, 5, 0)), Some((2022, 7)));
+        assert_eq!(today(utc(11, 30, 12, 0)), None);
+        assert_eq!(today(utc(12, 26, 5, 0)), None);
+    }
+
+    #[test]
+    fn test_latest_year() {
+        assert_eq!(latest_year(utc(12, 1, 5, 0)), 2022);
+        assert_eq!(latest_year(utc(12, 1, 4, 0)), 2021);
+        assert_eq!(latest_year(utc(6, 1, 0, 0)), 2021);
+    }
+
+    #[test]
+    fn test_resolve_day() {
+        let now = utc(12, 7, 5, 0);
+        assert_eq!(resolve_day(Some(3), 2021, now), Ok(3));
+        assert_eq!(
+            resolve_day(Some(26), 2022, now),
+            Err(DayError::OutOfRange(26))
+        );
+        assert_eq!(resolve_day(None, 2022, now), Ok(7));
+        assert_eq!(resolve_day(None, 2021, now), Err(DayError::NoneToday(2021)));
+        assert_eq!(
+            resolve_day(None, 2022, utc(11, 30, 12, 0)),
+            Err(DayError::NoneToday(2022))
+        );
+    }
+
+    #[test]
+    fn test_format_countdown() {
+        assert_eq!(format_countdown(Duration::from_secs(59)), "00:00:59");
+        assert_eq!(
+            format_countdown(Duration::from_millis(3_723_500)),
+            "01:02:04"
+        );
+        assert_eq!(format_countdown(Duration::from_secs(90_061)), "1d 01:01:01");
+    }
+}
@@ -0,0 +1,295 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::{
+    bench::{self, Stats},
+    handle_result,
+    memory::{self, MemoryStats},
+    SolveArgs, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET,
+};
+use serde::{Deserialize, Serialize};
+use std::{
+    fmt::Display,
+    time::{Duration, Instant},
+};
+
+/// Describes the puzzle a solution belongs to.
+pub struct Metadata {
+    pub day: u8,
+    pub title: &'static str,
+}
+
+/// A solution for one day.
+/// `src/bin/NN.rs` runs it on its own, the `aoc` runner runs it through the registry in `solutions/mod.rs`.
+pub trait Solution {
+    /// The puzzle input after parsing, shared by both parts.
+    type Input<'a>;
+    type AnswerOne: Display;
+    type AnswerTwo: Display;
+
+    fn metadata() -> Metadata;
+    fn parse(input: &str) -> Self::Input<'_>;
+    fn part_one(input: &Self::Input<'_>) -> Option<Self::AnswerOne>;
+    fn part_two(input: &Self::Input<'_>) -> Option<Self::AnswerTwo>;
+}
+
+/// Machine readable outcome of running one part, printed as a JSON line with `--json`.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PartRecord {
+    pub day: u8,
+    pub part: u8,
+    pub answer: Option<String>,
+    pub duration_ns: u64,
+    /// true if the part produced an answer that did not fail verification.
+    pub success: bool,
+    /// whether the answer matched the answer ledger, or None if it was not verified.
+    pub verified: Option<bool>,
+    /// timing statistics in bench mode. `duration_ns` is the median then.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bench: Option<Stats>,
+    /// heap usage with the `count-allocations` feature.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub memory: Option<MemoryStats>,
+}
+
+/// Machine readable outcome of parsing the input of a day, printed as a JSON line before the parts with `--json`.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ParseRecord {
+    pub day: u8,
+    pub parse_ns: u64,
+    /// timing statistics in bench mode. `parse_ns` is the median then.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bench: Option<Stats>,
+    /// heap usage with the `count-allocations` feature.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub memory: Option<MemoryStats>,
+}
+
+/// Outcome of running a solution.
+pub struct Report {
+    pub parse: ParseRecord,
+    pub parts: Vec<PartRecord>,
+}
+
+impl Report {
+    /// Returns the combined execution time of parsing and all parts that were run.
+    pub fn elapsed(&self) -> Duration {
+        Duration::from_nanos(self.parse.parse_ns)
+            + self
+                .parts
+                .iter()
+                .map(|part| Duration::from_nanos(part.duration_ns))
+                .sum::<Duration>()
+    }
+
+    /// Returns false if any answer did not match the answer ledger in verify mode.
+    pub fn verified(&self) -> bool {
+        self.parts.iter().all(|part| part.verified != Some(false))
+    }
+}
+
+/// A solution with its types erased, so that all days can be kept in one registry.
+pub struct Day {
+    pub metadata: Metadata,
+    pub run: fn(&str, &SolveArgs) -> Report,
+}
+
+impl Day {
+    pub fn of<S: Solution>() -> Day {
+        Day {
+            metadata: S::metadata(),
+            run: run::<S>,
+        }
+    }
+}
+
+/// Parses the input once, then runs the parts of `S` selected by `args` on it.
+/// Prints the parse time and each result and submits or verifies it if requested.
+pub fn run<S: Solution>(input: &str, args: &SolveArgs) -> Report {
+    let day = S::metadata().day;
+
+    // in bench mode, the time budget of the day is split between parsing and the parts.
+    let stages = 1 + (1..=2).filter(|&part| args.runs_part(part)).count() as u32;
+
+    let (input, parse) = match &args.bench {
+        Some(options) => {
+            let (input, stats, memory) =
+                bench::measure(|| S::parse(input), options, options.budget / stages);
+            (
+                input,
+                record_parse(day, stats.median(), Some(stats), memory, args),
+            )
+        }
+        None => {
+            let ((input, elapsed), memory) = memory::tracked(|| timed(|| S::parse(input)));
+            (input, record_parse(day, elapsed, None, memory, args))
+        }
+    };
+    let mut report = Report {
+        parse,
+        parts: vec![],
+    };
+
+    if args.runs_part(1) {
+        report
+            .parts
+            .push(run_part(day, 1, || S::part_one(&input), stages, args));
+    }
+
+    if args.runs_part(2) {
+        report
+            .parts
+            .push(run_part(day, 2, || S::part_two(&input), stages, args));
+    }
+
+    report
+}
+
+fn record_parse(
+    day: u8,
+    elapsed: Duration,
+    stats: Option<Stats>,
+    memory: Option<MemoryStats>,
+    args: &SolveArgs,
+) -> ParseRecord {
+    let record = ParseRecord {
+        day,
+        parse_ns: elapsed.as_nanos() as u64,
+        bench: stats,
+        memory,
+    };
+
+    if args.json {
+        println!("{}", serde_json::to_string(&record).unwrap());
+    } else {
+        print_parse(&record);
+    }
+    record
+}
+
+/// Runs one part once, or repeatedly in bench mode, and reports its result.
+fn run_part<T: Display>(
+    day: u8,
+    part: u8,
+    func: impl Fn() -> Option<T>,
+    stages: u32,
+    args: &SolveArgs,
+) -> PartRecord {
+    match &args.bench {
+        Some(options) => {
+            let (result, stats, memory) = bench::measure(func, options, options.budget / stages);
+            record_part(day, part, result, stats.median(), Some(stats), memory, args)
+        }
+        None => {
+            let ((result, elapsed), memory) = memory::tracked(|| timed(func));
+            record_part(day, part, result, elapsed, None, memory, args)
+        }
+    }
+}
+
+/// Prints the result of a part in the format selected by `args`, then verifies or submits it.
+pub fn report_part<T: Display>(
+    day: u8,
+    part: u8,
+    result: Option<T>,
+    elapsed: Duration,
+    memory: Option<MemoryStats>,
+    args: &SolveArgs,
+) -> PartRecord {
+    record_part(day, part, result, elapsed, None, memory, args)
+}
+
+fn record_part<T: Display>(
+    day: u8,
+    part: u8,
+    result: Option<T>,
+    elapsed: Duration,
+    stats: Option<Stats>,
+    memory: Option<MemoryStats>,
+    args: &SolveArgs,
+) -> PartRecord {
+    let answer = result.map(|value| value.to_string());
+    if !args.json {
+        let timing = describe_timing(elapsed, stats.as_ref(), memory.as_ref());
+        print_result(part, &answer, &timing);
+    }
+
+    let verified = handle_result(day, part, answer.as_deref(), args);
+    let record = PartRecord {
+        day,
+        part,
+        success: answer.is_some() && verified != Some(false),
+        answer,
+        duration_ns: elapsed.as_nanos() as u64,
+        verified,
+        bench: stats,
+        memory,
+    };
+
+    if args.json {
+        println!("{}", serde_json::to_string(&record).unwrap());
+    }
+    record
+}
+
+/// Prints the parse time of a day, or its statistics in bench mode.
+pub fn print_parse(record: &ParseRecord) {
+    let elapsed = Duration::from_nanos(record.parse_ns);
+    println!(
+        "🎄 {}Parse{} 🎄 {}",
+        ANSI_BOLD,
+        ANSI_RESET,
+        describe_timing(elapsed, record.bench.as_ref(), record.memory.as_ref())
+    );
+}
+
+/// Prints a part record the same way `run` prints the result when it is computed.
+pub fn print_part(record: &PartRecord) {
+    let elapsed = Duration::from_nanos(record.duration_ns);
+    let timing = describe_timing(elapsed, record.bench.as_ref(), record.memory.as_ref());
+    print_result(record.part, &record.answer, &timing);
+}
+
+/// Calls `func` and returns its result together with the time it took.
+pub fn timed<T>(func: impl FnOnce() -> T) -> (T, Duration) {
+    let timer = Instant::now();
+    let result = func();
+    (result, timer.elapsed())
+}
+
+/// Formats the time and memory a stage took, e.g. "(elapsed: 1.20ms, peak heap: 2.00 KiB, 3 allocs)".
+pub fn describe_timing(
+    elapsed: Duration,
+    stats: Option<&Stats>,
+    memory: Option<&MemoryStats>,
+) -> String {
+    let mut description = match stats {
+        Some(stats) => format!(
+            "median: {:.2?}, min: {:.2?}, mean: {:.2?} ± {:.2?}, {} runs",
+            stats.median(),
+            stats.min(),
+            stats.mean(),
+            stats.stddev(),
+            stats.samples
+        ),
+        None => format!("elapsed: {:.2?}", elapsed),
+    };
+    if let Some(memory) = memory {
+        description += &format!(", {}", memory);
+    }
+    format!("{}({}){}", ANSI_ITALIC, description, ANSI_RESET)
+}
+
+/// Prints the result of a part followed by a description of its timing.
+pub fn print_result<T: Display>(part: u8, result: &Option<T>, timing: &str) {
+    println!("🎄 {}Part {}{} 🎄", ANSI_BOLD, part, ANSI_RESET);
+    match result {
+        Some(result) => {
+            println!("{} {}", result, timing);
+        }
+        None => {
+            println!("not solved.");
+        }
+    }
+}
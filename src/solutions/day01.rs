@@ -0,0 +1,101 @@
+use itertools::Itertools;
+use crate::solution::{Metadata, Solution};
+
+/// An elve carrying calories
+struct Elve {
+    calories: Vec<i32>,
+}
+
+impl Elve {
+
+    /// Returns the sum of all calorie values carried by this elve
+    fn get_sum(&self) -> i32 {
+        self.calories.iter().sum()
+    }
+}
+
+/// Parses the given string and returns an elve struct
+fn parse_elve (input: &str) -> Elve {
+    let mut result = Elve {calories : vec![]};
+    for line in input.split('\n') {
+        // println!("Line is {:#?}", line.replace("\n", ""));
+        if line.is_empty() {
+            continue;
+        }
+        result.calories.push(line.replace('\n', "").parse::<i32>().unwrap());
+    }
+
+    result
+}
+
+pub fn part_one(input: &str) -> Option<u32> {
+
+    // Get all elves
+    let mut elves: Vec<Elve> = vec![];
+
+    for block in input.split("\n\n") {
+        // println!("Block is {:#?}", block.replace("\n\n", "").as_str());
+        elves.push(parse_elve(block.replace("\n\n", "").as_str()));
+    }
+
+    // Return the maximum total calories carried by any elve
+    Some(elves.iter().map(|elve| elve.get_sum()).max().unwrap() as u32)
+}
+
+pub fn part_two(input: &str) -> Option<u32> {
+
+    // get all elves
+    let mut elves: Vec<Elve> = vec![];
+
+    for block in input.split("\n\n") {
+        // println!("Block is {:#?}", block.replace("\n\n", "").as_str());
+        elves.push(parse_elve(block.replace("\n\n", "").as_str()));
+    }
+
+    // return the sum of the three elves carrying the most calories
+    Some(elves.iter().map(|elve| elve.get_sum()).sorted().skip(elves.len()-3).sum::<i32>() as u32)
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Input<'a> = &'a str;
+    type AnswerOne = u32;
+    type AnswerTwo = u32;
+
+    fn metadata() -> Metadata {
+        Metadata {
+            day: 1,
+            title: "Calorie Counting",
+        }
+    }
+
+    fn parse(input: &str) -> &str {
+        input
+    }
+
+    fn part_one(input: &&str) -> Option<u32> {
+        part_one(input)
+    }
+
+    fn part_two(input: &&str) -> Option<u32> {
+        part_two(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = crate::read_file("examples", 1).unwrap();
+        assert_eq!(part_one(&input), Some(24000));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = crate::read_file("examples", 1).unwrap();
+        assert_eq!(part_two(&input), Some(45000));
+    }
+}
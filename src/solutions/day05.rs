@@ -0,0 +1,115 @@
+use itertools::Itertools;
+use crate::solution::{Metadata, Solution};
+
+/// Parses the crate drawing at the top of the input into one stack per column
+fn parse_crates(input: &str) -> Vec<Vec<char>> {
+    let drawing = input.lines().take_while(|line| !line.is_empty()).collect_vec();
+    let (numbers, rows) = drawing.split_last().unwrap();
+    let mut crates: Vec<Vec<char>> = vec![vec![]; numbers.split_whitespace().count()];
+
+    for row in rows.iter().rev() {
+        for (idx, c) in row.chars().skip(1).step_by(4).enumerate() {
+            if c.is_ascii_alphabetic() {
+                crates[idx].push(c);
+            }
+        }
+    }
+
+    crates
+}
+
+/// Interprets a command for part 1 and executes the movements on crates
+fn do_command_pt1(command: &str, crates: &mut [Vec<char>]) {
+    let split = command.split_whitespace().collect_vec();
+    if split[0] =="move" {
+        let (num, src, dst) = (split[1].parse::<i32>().unwrap(), split[3].parse::<i32>().unwrap()-1, split[5].parse::<i32>().unwrap()-1);
+        for _ in 0..num {
+            let item = crates.get_mut(src as usize).unwrap().pop().unwrap();
+            crates.get_mut(dst as usize).unwrap().push(item);
+        }
+    }
+}
+
+/// Interprets a command for part 2 and executes the movements on crates
+fn do_command_pt2(command: &str, crates: &mut [Vec<char>]) {
+    let split = command.split_whitespace().collect_vec();
+    if split[0] =="move" {
+        let (num, src, dst) = (split[1].parse::<i32>().unwrap(), split[3].parse::<i32>().unwrap()-1, split[5].parse::<i32>().unwrap()-1);
+        let length = crates.get(src as usize).unwrap().len() - (num as usize);
+        let mut items = crates.get_mut(src as usize).unwrap().drain(length..).collect_vec();
+        crates.get_mut(dst as usize).unwrap().append(&mut items);
+    }
+}
+
+/// Returns a string concatenating all top crates
+fn get_top_crates(crates: &[Vec<char>]) -> String {
+    crates.iter().map(|vec| vec.last().unwrap()).join("")
+}
+
+pub fn part_one(input: &str) -> Option<String> {
+    let mut crates = parse_crates(input);
+
+    for line in input.lines() {
+        if !line.is_empty() {
+            do_command_pt1(line, &mut crates);
+        }
+    }
+
+    Some(get_top_crates(&crates))
+}
+
+pub fn part_two(input: &str) -> Option<String> {
+    let mut crates = parse_crates(input);
+
+    for line in input.lines() {
+        if !line.is_empty() {
+            do_command_pt2(line, &mut crates);
+        }
+    }
+
+    Some(get_top_crates(&crates))
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Input<'a> = &'a str;
+    type AnswerOne = String;
+    type AnswerTwo = String;
+
+    fn metadata() -> Metadata {
+        Metadata {
+            day: 5,
+            title: "Supply Stacks",
+        }
+    }
+
+    fn parse(input: &str) -> &str {
+        input
+    }
+
+    fn part_one(input: &&str) -> Option<String> {
+        part_one(input)
+    }
+
+    fn part_two(input: &&str) -> Option<String> {
+        part_two(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = crate::read_file("examples", 5).unwrap();
+        assert_eq!(part_one(&input), Some("CMZ".to_string()));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = crate::read_file("examples", 5).unwrap();
+        assert_eq!(part_two(&input), Some("MCD".to_string()));
+    }
+}
@@ -0,0 +1,78 @@
+use std::collections::HashSet;
+use crate::solution::{Metadata, Solution};
+
+/// Returns the first marker position where the last 4 characters are different
+fn get_first_marker_pos(input: &str) -> u32 {
+    let (_, (_, (_, (idx, _)))) = input.chars().zip(input.chars().skip(1).zip(input.chars().skip(2).zip(input.chars().skip(3).enumerate()))).find(|(c1, (c2, (c3, (_, c4))))| {let set: HashSet<char> = HashSet::from([*c1,*c2,*c3,*c4]); matches!(set.len(),4)}).unwrap();
+   (idx + 3 + 1) as u32
+}
+
+/// Returns the first packet position where the last 14 characters are different
+fn get_first_packet_pos(input: &str) -> u32 {
+    let mut index: u32 = 13;
+    let mut found = false;
+    while !found {
+        index += 1;
+        if HashSet::<char>::from_iter(input.chars().enumerate().filter_map(|(idx, c)| match index -14 <= idx as u32 && (idx as u32) < index {
+            true => Some(c),
+            false => None,
+        })).len() == 14 {
+            found = true;
+        }
+    }
+    index
+}
+
+pub fn part_one(input: &str) -> Option<u32> {
+    Some(get_first_marker_pos(input.lines().next().unwrap()))
+}
+
+pub fn part_two(input: &str) -> Option<u32> {
+    Some(get_first_packet_pos(input.lines().next().unwrap()))
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Input<'a> = &'a str;
+    type AnswerOne = u32;
+    type AnswerTwo = u32;
+
+    fn metadata() -> Metadata {
+        Metadata {
+            day: 6,
+            title: "Tuning Trouble",
+        }
+    }
+
+    fn parse(input: &str) -> &str {
+        input
+    }
+
+    fn part_one(input: &&str) -> Option<u32> {
+        part_one(input)
+    }
+
+    fn part_two(input: &&str) -> Option<u32> {
+        part_two(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = crate::read_file("examples", 6).unwrap();
+        assert_eq!(part_one(&input), Some(7));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = crate::read_file("examples", 6).unwrap();
+        assert_eq!(part_two(&input), Some(19));
+    }
+
+    crate::example_tests!(Day06: second, third, fourth, fifth);
+}
@@ -0,0 +1,154 @@
+use crate::solution::{Metadata, Solution};
+use itertools::Itertools;
+
+/// Represents a monkey. A monkey has a set of items, an operation it does for inspecting an item, a modification for the stress level, an action telling where to throw the item, and a count of inspections it made.
+struct Monkey {
+    items: Vec<usize>,
+    pub operation: Box<dyn Fn(usize) -> usize>,
+    pub modification: Box<dyn Fn(usize) -> usize>,
+    pub throw: Box<dyn Fn(usize) -> usize>,
+    inspection_count: u32,
+}
+
+/// Parses a monkey where relief is a function for the relief one experiences. Returns the monkey and the divisor of its test
+fn parse_monkey(block: &str, relief: fn(usize) -> usize) -> (Monkey, usize) {
+    let lines = block.lines().map(|line| line.trim()).collect_vec();
+    let last_number =
+        |line: &str| -> usize { line.split_whitespace().last().unwrap().parse().unwrap() };
+
+    let items = lines[1]
+        .trim_start_matches("Starting items: ")
+        .split(", ")
+        .map(|item| item.parse().unwrap())
+        .collect_vec();
+
+    // the operand is None if the operation uses the old value twice
+    let (operator, operand) = lines[2]
+        .trim_start_matches("Operation: new = old ")
+        .split_once(' ')
+        .unwrap();
+    let operand: Option<usize> = operand.parse().ok();
+    let operation: Box<dyn Fn(usize) -> usize> = match operator {
+        "*" => Box::new(move |item: usize| item * operand.unwrap_or(item)),
+        _ => Box::new(move |item: usize| item + operand.unwrap_or(item)),
+    };
+
+    let divisor = last_number(lines[3]);
+    let (if_true, if_false) = (last_number(lines[4]), last_number(lines[5]));
+
+    let monkey = Monkey {
+        items,
+        operation,
+        modification: Box::new(relief),
+        throw: Box::new(move |item: usize| {
+            if item.is_multiple_of(divisor) {
+                if_true
+            } else {
+                if_false
+            }
+        }),
+        inspection_count: 0,
+    };
+    (monkey, divisor)
+}
+
+/// Returns all monkeys where func is a function for the relief one experiences, and the product of all test divisors
+fn parse_monkeys(input: &str, func: fn(usize) -> usize) -> (Vec<Monkey>, usize) {
+    let (monkeys, divisors): (Vec<Monkey>, Vec<usize>) = input
+        .split("\n\n")
+        .filter(|block| !block.trim().is_empty())
+        .map(|block| parse_monkey(block, func))
+        .unzip();
+    (monkeys, divisors.iter().product())
+}
+
+fn simulate_rounds(number: u32, monkeys: &mut [Monkey], reduce: impl Fn(usize) -> usize) {
+    for _ in 0..number {
+        for i in 0..monkeys.len() {
+            while !monkeys[i].items.is_empty() {
+                let value: usize;
+                let destin: usize;
+                {
+                    let monkey = &mut monkeys[i];
+                    value = (monkey.modification)((monkey.operation)(monkey.items.remove(0)));
+                    destin = (monkey.throw)(value);
+                }
+                monkeys[destin].items.push(reduce(value));
+                monkeys[i].inspection_count += 1;
+            }
+        }
+    }
+}
+
+pub fn part_one(input: &str) -> Option<u32> {
+    let (mut monkeys, _) = parse_monkeys(input, |item: usize| -> usize { item / 3 });
+    simulate_rounds(20, &mut monkeys, |item| -> usize { item });
+    Some(
+        monkeys
+            .iter()
+            .map(|monkey| monkey.inspection_count)
+            .sorted()
+            .rev()
+            .take(2)
+            .product(),
+    )
+}
+
+pub fn part_two(input: &str) -> Option<u64> {
+    let (mut monkeys, modulus) = parse_monkeys(input, |item: usize| -> usize { item });
+    // all tests only depend on the item modulo the product of their divisors
+    simulate_rounds(10000, &mut monkeys, |item| -> usize { item % modulus });
+    Some(
+        monkeys
+            .iter()
+            .map(|monkey| monkey.inspection_count as u64)
+            .sorted()
+            .rev()
+            .take(2)
+            .product(),
+    )
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input<'a> = &'a str;
+    type AnswerOne = u32;
+    type AnswerTwo = u64;
+
+    fn metadata() -> Metadata {
+        Metadata {
+            day: 11,
+            title: "Monkey in the Middle",
+        }
+    }
+
+    fn parse(input: &str) -> &str {
+        input
+    }
+
+    fn part_one(input: &&str) -> Option<u32> {
+        part_one(input)
+    }
+
+    fn part_two(input: &&str) -> Option<u64> {
+        part_two(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = crate::read_file("examples", 11).unwrap();
+        assert_eq!(part_one(&input), Some(10605));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = crate::read_file("examples", 11).unwrap();
+        assert_eq!(part_two(&input), Some(2713310158));
+    }
+}
@@ -0,0 +1,128 @@
+/*
+ * Solutions are registered here so that the `aoc` runner can call every day in-process.
+ * `cargo scaffold` adds new days to this file, and days scaffolded with a year to `y<year>/mod.rs`.
+ * The `aoc_skip` attributes let the runner leave out days that do not compile, see `src/compile.rs`.
+ */
+use crate::{config, solution::Day};
+
+#[cfg(not(aoc_skip = "day01"))]
+pub mod day01;
+#[cfg(not(aoc_skip = "day02"))]
+pub mod day02;
+#[cfg(not(aoc_skip = "day03"))]
+pub mod day03;
+#[cfg(not(aoc_skip = "day04"))]
+pub mod day04;
+#[cfg(not(aoc_skip = "day05"))]
+pub mod day05;
+#[cfg(not(aoc_skip = "day06"))]
+pub mod day06;
+#[cfg(not(aoc_skip = "day07"))]
+pub mod day07;
+#[cfg(not(aoc_skip = "day08"))]
+pub mod day08;
+#[cfg(not(aoc_skip = "day09"))]
+pub mod day09;
+#[cfg(not(aoc_skip = "day10"))]
+pub mod day10;
+#[cfg(not(aoc_skip = "day11"))]
+pub mod day11;
+#[cfg(not(aoc_skip = "day12"))]
+pub mod day12;
+#[cfg(not(aoc_skip = "day13"))]
+pub mod day13;
+#[cfg(not(aoc_skip = "day14"))]
+pub mod day14;
+#[cfg(not(aoc_skip = "day15"))]
+pub mod day15;
+#[cfg(not(aoc_skip = "day16"))]
+pub mod day16;
+#[cfg(not(aoc_skip = "day17"))]
+pub mod day17;
+#[cfg(not(aoc_skip = "day18"))]
+pub mod day18;
+#[cfg(not(aoc_skip = "day19"))]
+pub mod day19;
+#[cfg(not(aoc_skip = "day20"))]
+pub mod day20;
+#[cfg(not(aoc_skip = "day21"))]
+pub mod day21;
+#[cfg(not(aoc_skip = "day22"))]
+pub mod day22;
+#[cfg(not(aoc_skip = "day23"))]
+pub mod day23;
+#[cfg(not(aoc_skip = "day24"))]
+pub mod day24;
+#[cfg(not(aoc_skip = "day25"))]
+pub mod day25;
+
+type Registry = fn() -> Vec<Day>;
+
+/// The years with their own registry in `y<year>/mod.rs`.
+const YEARS: &[(u16, Registry)] = &[];
+
+/// Returns the solutions of the configured year. Without a registry of that year, the days
+/// scaffolded without a year are returned.
+pub fn registry() -> Vec<Day> {
+    let year = config::get().year;
+    YEARS
+        .iter()
+        .find(|&&(registered, _)| Some(registered) == year)
+        .map_or_else(days, |(_, registry)| registry())
+}
+
+/// Returns the solutions scaffolded without a year.
+fn days() -> Vec<Day> {
+    vec![
+        #[cfg(not(aoc_skip = "day01"))]
+        Day::of::<day01::Day01>(),
+        #[cfg(not(aoc_skip = "day02"))]
+        Day::of::<day02::Day02>(),
+        #[cfg(not(aoc_skip = "day03"))]
+        Day::of::<day03::Day03>(),
+        #[cfg(not(aoc_skip = "day04"))]
+        Day::of::<day04::Day04>(),
+        #[cfg(not(aoc_skip = "day05"))]
+        Day::of::<day05::Day05>(),
+        #[cfg(not(aoc_skip = "day06"))]
+        Day::of::<day06::Day06>(),
+        #[cfg(not(aoc_skip = "day07"))]
+        Day::of::<day07::Day07>(),
+        #[cfg(not(aoc_skip = "day08"))]
+        Day::of::<day08::Day08>(),
+        #[cfg(not(aoc_skip = "day09"))]
+        Day::of::<day09::Day09>(),
+        #[cfg(not(aoc_skip = "day10"))]
+        Day::of::<day10::Day10>(),
+        #[cfg(not(aoc_skip = "day11"))]
+        Day::of::<day11::Day11>(),
+        #[cfg(not(aoc_skip = "day12"))]
+        Day::of::<day12::Day12>(),
+        #[cfg(not(aoc_skip = "day13"))]
+        Day::of::<day13::Day13>(),
+        #[cfg(not(aoc_skip = "day14"))]
+        Day::of::<day14::Day14>(),
+        #[cfg(not(aoc_skip = "day15"))]
+        Day::of::<day15::Day15>(),
+        #[cfg(not(aoc_skip = "day16"))]
+        Day::of::<day16::Day16>(),
+        #[cfg(not(aoc_skip = "day17"))]
+        Day::of::<day17::Day17>(),
+        #[cfg(not(aoc_skip = "day18"))]
+        Day::of::<day18::Day18>(),
+        #[cfg(not(aoc_skip = "day19"))]
+        Day::of::<day19::Day19>(),
+        #[cfg(not(aoc_skip = "day20"))]
+        Day::of::<day20::Day20>(),
+        #[cfg(not(aoc_skip = "day21"))]
+        Day::of::<day21::Day21>(),
+        #[cfg(not(aoc_skip = "day22"))]
+        Day::of::<day22::Day22>(),
+        #[cfg(not(aoc_skip = "day23"))]
+        Day::of::<day23::Day23>(),
+        #[cfg(not(aoc_skip = "day24"))]
+        Day::of::<day24::Day24>(),
+        #[cfg(not(aoc_skip = "day25"))]
+        Day::of::<day25::Day25>(),
+    ]
+}
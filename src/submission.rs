@@ -0,0 +1,291 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::config;
+use chrono::{DateTime, FixedOffset, Local};
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::{
+    fmt::Display,
+    fs::{self, create_dir_all},
+    io,
+    path::PathBuf,
+    time::Duration,
+};
+
+/// How Advent of Code judged a submitted answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Verdict {
+    Correct,
+    /// wrong, and the answer is too high.
+    TooHigh,
+    /// wrong, and the answer is too low.
+    TooLow,
+    /// wrong, without a hint.
+    Incorrect,
+    /// not judged, because the last answer was submitted too recently.
+    TooRecent,
+    /// not judged, because the part was already solved.
+    AlreadySolved,
+    /// the response could not be classified.
+    Unknown,
+}
+
+impl Verdict {
+    pub fn is_wrong(&self) -> bool {
+        matches!(
+            self,
+            Verdict::TooHigh | Verdict::TooLow | Verdict::Incorrect
+        )
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Verdict::Correct => "correct",
+            Verdict::TooHigh => "too high",
+            Verdict::TooLow => "too low",
+            Verdict::Incorrect => "incorrect",
+            Verdict::TooRecent => "answered too recently",
+            Verdict::AlreadySolved => "already solved",
+            Verdict::Unknown => "unknown response",
+        }
+    }
+}
+
+/// A classified response to a submission.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Response {
+    pub verdict: Verdict,
+    /// how long to wait before the next submission, if the response says so.
+    pub wait: Option<Duration>,
+    pub message: String,
+}
+
+/// Classifies the message of a submission response, see `client::Client::submit`.
+pub fn parse_response(message: &str) -> Response {
+    let verdict = if message.contains("That's the right answer") {
+        Verdict::Correct
+    } else if message.contains("That's not the right answer") {
+        if message.contains("your answer is too high") {
+            Verdict::TooHigh
+        } else if message.contains("your answer is too low") {
+            Verdict::TooLow
+        } else {
+            Verdict::Incorrect
+        }
+    } else if message.contains("You gave an answer too recently") {
+        Verdict::TooRecent
+    } else if message.contains("Did you already complete it") {
+        Verdict::AlreadySolved
+    } else {
+        Verdict::Unknown
+    };
+
+    Response {
+        verdict,
+        wait: parse_wait(message),
+        message: message.to_string(),
+    }
+}
+
+/// Reads "You have 1m 20s left to wait" and "Please wait one minute before trying again".
+fn parse_wait(message: &str) -> Option<Duration> {
+    let left = Regex::new(r"You have (?:(\d+)m )?(\d+)s left to wait").unwrap();
+    if let Some(captures) = left.captures(message) {
+        let minutes: u64 = captures
+            .get(1)
+            .map_or(Some(0), |m| m.as_str().parse().ok())?;
+        let seconds: u64 = captures[2].parse().ok()?;
+        return Some(Duration::from_secs(minutes * 60 + seconds));
+    }
+
+    let retry = Regex::new(r"(?i)please wait (one|\d+) minutes? before trying again").unwrap();
+    let captures = retry.captures(message)?;
+    let minutes: u64 = match &captures[1] {
+        "one" => 1,
+        count => count.parse().ok()?,
+    };
+    Some(Duration::from_secs(minutes * 60))
+}
+
+/// One submitted answer.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Submission {
+    /// the event the answer was submitted to, missing in entries logged before it was recorded.
+    #[serde(default)]
+    pub year: Option<u16>,
+    pub day: u8,
+    pub part: u8,
+    pub answer: String,
+    pub verdict: Verdict,
+    /// RFC 3339 timestamp of the submission.
+    pub date: String,
+}
+
+/// Local record of submissions, stored in `answers/submissions.toml` of the data folder.
+/// There is one state for all years, as Advent of Code enforces the cooldown across events.
+#[derive(Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SubmissionState {
+    /// RFC 3339 timestamp until which no answers are submitted.
+    pub blocked_until: Option<String>,
+    #[serde(default)]
+    pub submissions: Vec<Submission>,
+}
+
+#[derive(Debug)]
+pub enum StateError {
+    IoError(io::Error),
+    ParseError(toml::de::Error),
+    SerializeError(toml::ser::Error),
+}
+
+impl Display for StateError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            StateError::IoError(e) => write!(f, "could not access submission state: {}", e),
+            StateError::ParseError(e) => write!(f, "could not parse submission state: {}", e),
+            StateError::SerializeError(e) => {
+                write!(f, "could not serialize submission state: {}", e)
+            }
+        }
+    }
+}
+
+impl SubmissionState {
+    /// Loads the submission state. A missing file is treated as an empty state.
+    pub fn load() -> Result<SubmissionState, StateError> {
+        match fs::read_to_string(get_state_path()) {
+            Ok(content) => toml::from_str(&content).map_err(StateError::ParseError),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(SubmissionState::default()),
+            Err(e) => Err(StateError::IoError(e)),
+        }
+    }
+
+    pub fn save(&self) -> Result<(), StateError> {
+        let content = toml::to_string(self).map_err(StateError::SerializeError)?;
+        let path = get_state_path();
+        if let Some(dir) = path.parent() {
+            create_dir_all(dir).map_err(StateError::IoError)?;
+        }
+        fs::write(path, content).map_err(StateError::IoError)
+    }
+
+    /// Returns how long submissions are still blocked at `now`, or None if they are allowed.
+    pub fn remaining_wait(&self, now: DateTime<Local>) -> Option<Duration> {
+        let blocked_until = self.blocked_until.as_deref()?;
+        let blocked_until = DateTime::parse_from_rfc3339(blocked_until).ok()?;
+        (blocked_until - now.with_timezone(blocked_until.offset()))
+            .to_std()
+            .ok()
+            .filter(|wait| !wait.is_zero())
+    }
+
+    /// Records a submission made at `now` and blocks further submissions for the reported wait time.
+    pub fn record(
+        &mut self,
+        year: u16,
+        day: u8,
+        part: u8,
+        answer: &str,
+        response: &Response,
+        now: DateTime<Local>,
+    ) {
+        self.submissions.push(Submission {
+            year: Some(year),
+            day,
+            part,
+            answer: answer.to_string(),
+            verdict: response.verdict,
+            date: now.to_rfc3339(),
+        });
+        if let Some(wait) = response.wait {
+            let until: DateTime<FixedOffset> =
+                (now + chrono::Duration::from_std(wait).unwrap()).into();
+            self.blocked_until = Some(until.to_rfc3339());
+        }
+    }
+}
+
+/// Returns the path of the submission state, which is independent of the configured year.
+fn get_state_path() -> PathBuf {
+    config::get().dir("answers", None).join("submissions.toml")
+}
+
+/// Formats a wait time like "1m 20s".
+pub fn format_wait(wait: Duration) -> String {
+    let seconds = wait.as_secs() + u64::from(wait.subsec_nanos() > 0);
+    match (seconds / 60, seconds % 60) {
+        (0, seconds) => format!("{}s", seconds),
+        (minutes, 0) => format!("{}m", minutes),
+        (minutes, seconds) => format!("{}m {}s", minutes, seconds),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_parse_response() {
+        let response = parse_response("That's the right answer! You are one gold star closer to collecting enough star fruit. [Continue to Part Two]");
+        assert_eq!(response.verdict, Verdict::Correct);
+        assert_eq!(response.wait, None);
+
+        let response = parse_response("That's not the right answer; your answer is too high. If you're stuck, make sure you're using the full input data. Please wait one minute before trying again. [Return to Day 7]");
+        assert_eq!(response.verdict, Verdict::TooHigh);
+        assert_eq!(response.wait, Some(Duration::from_secs(60)));
+
+        let response = parse_response("That's not the right answer; your answer is too low. please wait 5 minutes before trying again.");
+        assert_eq!(response.verdict, Verdict::TooLow);
+        assert_eq!(response.wait, Some(Duration::from_secs(300)));
+
+        let response = parse_response("That's not the right answer. If you're stuck, ...");
+        assert_eq!(response.verdict, Verdict::Incorrect);
+
+        let response = parse_response("You gave an answer too recently; you have to wait after submitting an answer before trying again. You have 1m 20s left to wait. [Return to Day 7]");
+        assert_eq!(response.verdict, Verdict::TooRecent);
+        assert_eq!(response.wait, Some(Duration::from_secs(80)));
+        assert_eq!(
+            parse_response("You have 34s left to wait.").wait,
+            Some(Duration::from_secs(34))
+        );
+
+        let response = parse_response("You don't seem to be solving the right level. Did you already complete it? [Return to Day 7]");
+        assert_eq!(response.verdict, Verdict::AlreadySolved);
+        assert_eq!(parse_response("Bad Gateway").verdict, Verdict::Unknown);
+    }
+
+    #[test]
+    fn test_cooldown() {
+        let now = Local.with_ymd_and_hms(2022, 12, 7, 6, 0, 0).unwrap();
+        let mut state = SubmissionState::default();
+        assert_eq!(state.remaining_wait(now), None);
+
+        let response = parse_response(
+            "That's not the right answer. Please wait one minute before trying again.",
+        );
+        state.record(2022, 7, 1, "42", &response, now);
+        assert_eq!(state.submissions[0].verdict, Verdict::Incorrect);
+        assert_eq!(
+            state.remaining_wait(now + chrono::Duration::seconds(20)),
+            Some(Duration::from_secs(40))
+        );
+        assert_eq!(
+            state.remaining_wait(now + chrono::Duration::seconds(60)),
+            None
+        );
+
+        let content = toml::to_string(&state).unwrap();
+        assert_eq!(toml::from_str::<SubmissionState>(&content).unwrap(), state);
+    }
+
+    #[test]
+    fn test_format_wait() {
+        assert_eq!(format_wait(Duration::from_secs(34)), "34s");
+        assert_eq!(format_wait(Duration::from_millis(79_500)), "1m 20s");
+        assert_eq!(format_wait(Duration::from_secs(300)), "5m");
+    }
+}
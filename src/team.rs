@@ -0,0 +1,259 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::{
+    config,
+    ledger::Ledger,
+    runner::{self, Outcome, Status},
+};
+use rayon::prelude::*;
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+/// One team member's input of a day, stored as `src/inputs/NN/<name>.txt`.
+/// Expected answers are read from `src/inputs/NN/<name>.toml` in the format of the answer ledger.
+pub struct TeamInput {
+    pub name: String,
+    pub path: PathBuf,
+    pub expected: Option<Ledger>,
+}
+
+/// Result of one part on one input.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Cell {
+    Pass(String),
+    Fail {
+        answer: Option<String>,
+        expected: String,
+    },
+    /// the part produced an answer, but there is no expected answer to compare with.
+    Unchecked(String),
+    Unsolved,
+    Crashed,
+    TimedOut,
+}
+
+impl Cell {
+    pub fn is_failure(&self) -> bool {
+        matches!(self, Cell::Fail { .. } | Cell::Crashed | Cell::TimedOut)
+    }
+
+    fn text(&self) -> String {
+        match self {
+            Cell::Pass(answer) => format!("✓ {}", answer),
+            Cell::Fail { answer, expected } => format!(
+                "✗ {} (expected {})",
+                answer.as_deref().unwrap_or("nothing"),
+                expected
+            ),
+            Cell::Unchecked(answer) => format!("? {}", answer),
+            Cell::Unsolved => "not solved".into(),
+            Cell::Crashed => "crashed".into(),
+            Cell::TimedOut => "timed out".into(),
+        }
+    }
+}
+
+pub fn get_team_dir(day: u8) -> PathBuf {
+    let config = config::get();
+    config
+        .dir("inputs", config.year)
+        .join(format!("{:02}", day))
+}
+
+/// Finds all inputs in the team directory of `day`, sorted by name.
+pub fn discover(day: u8) -> io::Result<Vec<TeamInput>> {
+    let mut inputs = vec![];
+    for entry in fs::read_dir(get_team_dir(day))? {
+        let path = entry?.path();
+        if path.extension().is_none_or(|ext| ext != "txt") {
+            continue;
+        }
+        let name = path
+            .file_stem()
+            .map(|stem| stem.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        let expected = read_expected(&path.with_extension("toml"))?;
+        inputs.push(TeamInput {
+            name,
+            path,
+            expected,
+        });
+    }
+    inputs.sort_by(|a, b| a.name.cmp(&b.name));
+    Ok(inputs)
+}
+
+fn read_expected(path: &Path) -> io::Result<Option<Ledger>> {
+    match fs::read_to_string(path) {
+        Ok(content) => Ledger::parse(&content).map(Some).map_err(|e| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("{}: {}", path.display(), e),
+            )
+        }),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(None),
+        Err(e) => Err(e),
+    }
+}
+
+/// Runs `day` on each input in its own worker process, on up to `jobs` workers at a time.
+/// Returns the outcomes in the order of `inputs`.
+pub fn run_inputs(
+    exe: &Path,
+    day: u8,
+    inputs: &[TeamInput],
+    worker_args: &[String],
+    timeout: Duration,
+    jobs: usize,
+) -> Vec<Outcome> {
+    let pool = rayon::ThreadPoolBuilder::new()
+        .num_threads(jobs)
+        .build()
+        .expect("could not start runner threads");
+
+    pool.install(|| {
+        inputs
+            .par_iter()
+            .map(|input| {
+                let mut args = worker_args.to_vec();
+                args.extend(["--input".to_string(), input.path.display().to_string()]);
+                runner::run_day(exe, day, &args, timeout).unwrap_or_else(|e| Outcome {
+                    status: Status::Crashed,
+                    stderr: format!("failed to start worker for {}: {}\n", input.name, e),
+                    ..Outcome::unsolved(day)
+                })
+            })
+            .collect()
+    })
+}
+
+/// Checks the answer of `part` in `outcome` against the expected answers.
+pub fn evaluate(outcome: &Outcome, expected: Option<&Ledger>, part: u8) -> Cell {
+    let record = outcome.parts.iter().find(|record| record.part == part);
+    let expected = expected.and_then(|ledger| ledger.part(part).accepted.clone());
+
+    match (record.and_then(|r| r.answer.clone()), expected) {
+        (Some(answer), Some(expected)) if answer == expected => Cell::Pass(answer),
+        (answer, Some(expected)) if record.is_some() => Cell::Fail { answer, expected },
+        (Some(answer), None) => Cell::Unchecked(answer),
+        _ if record.is_some() => Cell::Unsolved,
+        _ => match outcome.status {
+            Status::Crashed => Cell::Crashed,
+            Status::TimedOut => Cell::TimedOut,
+            _ => Cell::Unsolved,
+        },
+    }
+}
+
+/// Renders one row per input with a column per part.
+pub fn render_matrix(rows: &[(String, [Cell; 2])]) -> String {
+    let header = ["Input".to_string(), "Part 1".into(), "Part 2".into()];
+    let lines: Vec<[String; 3]> = rows
+        .iter()
+        .map(|(name, cells)| [name.clone(), cells[0].text(), cells[1].text()])
+        .collect();
+
+    let widths: Vec<usize> = (0..3)
+        .map(|column| {
+            lines
+                .iter()
+                .chain([&header])
+                .map(|line| line[column].chars().count())
+                .max()
+                .unwrap_or(0)
+        })
+        .collect();
+
+    let render = |line: &[String; 3]| {
+        let cells: Vec<String> = line
+            .iter()
+            .zip(&widths)
+            .map(|(cell, width)| format!("{}{}", cell, " ".repeat(width - cell.chars().count())))
+            .collect();
+        format!("| {} |\n", cells.join(" | "))
+    };
+
+    let separator: Vec<String> = widths.iter().map(|width| "-".repeat(*width)).collect();
+    let mut matrix = render(&header);
+    matrix += &format!("| {} |\n", separator.join(" | "));
+    lines.iter().for_each(|line| matrix += &render(line));
+    matrix
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solution::PartRecord;
+
+    fn outcome(status: Status, answers: &[Option<&str>]) -> Outcome {
+        Outcome {
+            status,
+            parts: answers
+                .iter()
+                .enumerate()
+                .map(|(index, answer)| PartRecord {
+                    day: 22,
+                    part: index as u8 + 1,
+                    answer: answer.map(|a| a.to_string()),
+                    duration_ns: 0,
+                    success: answer.is_some(),
+                    verified: None,
+                    bench: None,
+                    memory: None,
+                })
+                .collect(),
+            ..Outcome::unsolved(22)
+        }
+    }
+
+    #[test]
+    fn test_evaluate() {
+        let mut expected = Ledger::default();
+        expected.accept(1, "6032");
+        expected.accept(2, "5031");
+
+        let solved = outcome(Status::Solved, &[Some("6032"), Some("4000")]);
+        assert_eq!(
+            evaluate(&solved, Some(&expected), 1),
+            Cell::Pass("6032".into())
+        );
+        assert!(evaluate(&solved, Some(&expected), 2).is_failure());
+        assert_eq!(evaluate(&solved, None, 2), Cell::Unchecked("4000".into()));
+
+        let crashed = outcome(Status::Crashed, &[Some("6032")]);
+        assert_eq!(
+            evaluate(&crashed, Some(&expected), 1),
+            Cell::Pass("6032".into())
+        );
+        assert_eq!(evaluate(&crashed, Some(&expected), 2), Cell::Crashed);
+
+        let unsolved = outcome(Status::Unsolved, &[Some("6032"), None]);
+        assert_eq!(evaluate(&unsolved, None, 2), Cell::Unsolved);
+    }
+
+    #[test]
+    fn test_render_matrix() {
+        let rows = vec![
+            (
+                "alice".to_string(),
+                [Cell::Pass("1".into()), Cell::TimedOut],
+            ),
+            (
+                "bob".to_string(),
+                [Cell::Unsolved, Cell::Unchecked("22".into())],
+            ),
+        ];
+        assert_eq!(
+            render_matrix(&rows),
+            "| Input | Part 1     | Part 2    |\n\
+             | ----- | ---------- | --------- |\n\
+             | alice | ✓ 1        | timed out |\n\
+             | bob   | not solved | ? 22      |\n"
+        );
+    }
+}
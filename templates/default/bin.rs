@@ -0,0 +1,12 @@
+use aoc::MODULE::DayDAY_PADDED;
+
+fn main() {
+    let args = aoc::SolveArgs {
+        year: DATA_YEAR,
+        ..aoc::SolveArgs::from_env()
+    };
+    let input = &args.read_input(DAY);
+    if !aoc::solution::run::<DayDAY_PADDED>(input, &args).verified() {
+        std::process::exit(1);
+    }
+}
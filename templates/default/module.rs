@@ -0,0 +1,57 @@
+use crate::solution::{Metadata, Solution};
+
+pub fn part_one(_input: &str) -> Option<ANSWER_TYPE> {
+    None
+}
+
+pub fn part_two(_input: &str) -> Option<ANSWER_TYPE> {
+    None
+}
+
+pub struct DayDAY_PADDED;
+
+impl Solution for DayDAY_PADDED {
+    // parse into your own type here to share the parsed input between both parts.
+    type Input<'a> = &'a str;
+    type AnswerOne = ANSWER_TYPE;
+    type AnswerTwo = ANSWER_TYPE;
+
+    fn metadata() -> Metadata {
+        Metadata {
+            day: DAY,
+            // https://adventofcode.com/YEAR/day/DAY
+            title: "",
+        }
+    }
+
+    fn parse(input: &str) -> &str {
+        input
+    }
+
+    fn part_one(input: &&str) -> Option<ANSWER_TYPE> {
+        part_one(input)
+    }
+
+    fn part_two(input: &&str) -> Option<ANSWER_TYPE> {
+        part_two(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[ignore = "needs the example, run `cargo example DAY` or paste it and remove this line"]
+    fn test_part_one() {
+        let input = crate::read_example(DATA_YEAR, DAY);
+        assert_eq!(part_one(&input), None);
+    }
+
+    #[test]
+    #[ignore = "needs the example, run `cargo example DAY` or paste it and remove this line"]
+    fn test_part_two() {
+        let input = crate::read_example(DATA_YEAR, DAY);
+        assert_eq!(part_two(&input), None);
+    }
+}
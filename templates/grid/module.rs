@@ -0,0 +1,57 @@
+use crate::helpers::Grid;
+use crate::solution::{Metadata, Solution};
+
+pub fn part_one(input: &str) -> Option<ANSWER_TYPE> {
+    DayDAY_PADDED::part_one(&DayDAY_PADDED::parse(input))
+}
+
+pub fn part_two(input: &str) -> Option<ANSWER_TYPE> {
+    DayDAY_PADDED::part_two(&DayDAY_PADDED::parse(input))
+}
+
+pub struct DayDAY_PADDED;
+
+impl Solution for DayDAY_PADDED {
+    type Input<'a> = Grid;
+    type AnswerOne = ANSWER_TYPE;
+    type AnswerTwo = ANSWER_TYPE;
+
+    fn metadata() -> Metadata {
+        Metadata {
+            day: DAY,
+            // https://adventofcode.com/YEAR/day/DAY
+            title: "",
+        }
+    }
+
+    fn parse(input: &str) -> Grid {
+        Grid::parse(input)
+    }
+
+    fn part_one(_grid: &Grid) -> Option<ANSWER_TYPE> {
+        None
+    }
+
+    fn part_two(_grid: &Grid) -> Option<ANSWER_TYPE> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[ignore = "needs the example, run `cargo example DAY` or paste it and remove this line"]
+    fn test_part_one() {
+        let input = crate::read_example(DATA_YEAR, DAY);
+        assert_eq!(part_one(&input), None);
+    }
+
+    #[test]
+    #[ignore = "needs the example, run `cargo example DAY` or paste it and remove this line"]
+    fn test_part_two() {
+        let input = crate::read_example(DATA_YEAR, DAY);
+        assert_eq!(part_two(&input), None);
+    }
+}